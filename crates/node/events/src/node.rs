@@ -306,8 +306,29 @@ impl NodeState {
             PrunerEvent::Started { tip_block_number } => {
                 info!(tip_block_number, "Pruner started");
             }
-            PrunerEvent::Finished { tip_block_number, elapsed, stats } => {
-                info!(tip_block_number, ?elapsed, ?stats, "Pruner finished");
+            PrunerEvent::SegmentStarted { segment, to_block, previous_checkpoint } => {
+                debug!(?segment, to_block, ?previous_checkpoint, "Pruner segment started");
+            }
+            PrunerEvent::SegmentFinished { segment, pruned, done, percent_complete } => {
+                debug!(?segment, pruned, done, percent_complete, "Pruner segment finished");
+            }
+            PrunerEvent::Finished {
+                tip_block_number,
+                elapsed,
+                stats,
+                budget_consumers,
+                effective_delete_limit,
+                delete_limit_remaining,
+            } => {
+                info!(
+                    tip_block_number,
+                    ?elapsed,
+                    ?stats,
+                    ?budget_consumers,
+                    effective_delete_limit,
+                    delete_limit_remaining,
+                    "Pruner finished"
+                );
             }
         }
     }
@@ -13,7 +13,7 @@ pub mod tx_type;
 pub use tx_type::OpTxType;
 
 use reth_node_types::NodePrimitives;
-use reth_primitives::{Block, Receipt, TransactionSigned};
+use reth_primitives::{Block, Header, Receipt, TransactionSigned};
 
 /// Optimism primitive types.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -21,6 +21,7 @@ pub struct OpPrimitives;
 
 impl NodePrimitives for OpPrimitives {
     type Block = Block;
+    type BlockHeader = Header;
     type SignedTx = TransactionSigned;
     type TxType = OpTxType;
     type Receipt = Receipt;
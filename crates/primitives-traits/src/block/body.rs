@@ -1,17 +1,58 @@
 //! Block body abstraction.
 
-use alloc::fmt;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    vec::Vec,
+};
 
 use alloy_consensus::Transaction;
+use alloy_eips::{
+    eip1559::BaseFeeParams,
+    eip2718::Encodable2718,
+    eip4844::DATA_GAS_PER_BLOB,
+    eip4895::{Withdrawal, Withdrawals},
+    eip7702::SignedAuthorization,
+};
+use alloy_primitives::{
+    keccak256, map::HashMap, Address, Bloom, BloomInput, Bytes, Sealable, B256, U256,
+};
+use alloy_trie::{
+    proof::ProofRetainer,
+    root::{adjust_index_for_rlp, ordered_trie_root, ordered_trie_root_with_encoder},
+    HashBuilder, Nibbles,
+};
+#[cfg(feature = "std")]
+use rayon::prelude::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
 use reth_codecs::Compact;
 
-use crate::{FullSignedTx, InMemorySize, MaybeSerde};
+use crate::{
+    BlockHeader, BodyValidationError, FullSignedTx, GotExpected, InMemorySize, MaybeBlobSidecar,
+    MaybeSerde, RecoveryError, SignedTransaction,
+};
 
 /// Helper trait that unifies all behaviour required by transaction to support full node operations.
 pub trait FullBlockBody: BlockBody<Transaction: FullSignedTx> + Compact {}
 
 impl<T> FullBlockBody for T where T: BlockBody<Transaction: FullSignedTx> + Compact {}
 
+/// Header fields derived from a block body, see [`BlockBody::header_fields`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BodyHeaderFields {
+    /// Root of the transactions trie.
+    pub transactions_root: B256,
+    /// Hash of the RLP encoded ommers.
+    pub ommers_hash: B256,
+    /// Root of the withdrawals trie, if the body contains withdrawals.
+    pub withdrawals_root: Option<B256>,
+    /// Total gas limit of all transactions.
+    pub transactions_gas_limit: u64,
+    /// Total blob gas used by all blob transactions.
+    pub blob_gas_used: u64,
+}
+
 /// Abstraction for block's body.
 #[auto_impl::auto_impl(&, Arc)]
 pub trait BlockBody:
@@ -32,6 +73,603 @@ pub trait BlockBody:
     // todo: requires trait for signed transaction
     type Transaction: Transaction;
 
+    /// Header type of the ommers (uncles) included in the block.
+    type OmmerHeader: BlockHeader;
+
     /// Returns reference to transactions in block.
     fn transactions(&self) -> &[Self::Transaction];
+
+    /// Returns reference to ommers (uncles) in block.
+    fn ommers(&self) -> &[Self::OmmerHeader];
+
+    /// Returns reference to withdrawals in block, if any.
+    fn withdrawals(&self) -> Option<&Withdrawals>;
+
+    /// Returns withdrawals in block as a slice, if any.
+    ///
+    /// Convenient for indexed access, e.g. when validating withdrawal indices.
+    fn withdrawals_slice(&self) -> Option<&[Withdrawal]> {
+        self.withdrawals().map(|withdrawals| withdrawals.as_slice())
+    }
+
+    /// Returns all transactions in the block that call the given `contract` address.
+    ///
+    /// Contract creation transactions are never included.
+    fn transactions_to(&self, contract: Address) -> Vec<&Self::Transaction> {
+        self.transactions().iter().filter(|tx| tx.kind().to() == Some(&contract)).collect()
+    }
+
+    /// Returns all transactions in the block sent by the given `sender`.
+    ///
+    /// Unlike [`Self::transactions_to`], this recovers the sender of every transaction. The
+    /// transactions whose sender can't be recovered are never included.
+    fn transactions_by_sender(&self, sender: Address) -> Vec<&Self::Transaction>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        self.transactions().iter().filter(|tx| tx.recover_signer() == Some(sender)).collect()
+    }
+
+    /// Returns the number of transactions sent to each recipient in the block. Contract creation
+    /// transactions are skipped.
+    fn recipient_counts(&self) -> HashMap<Address, usize> {
+        let mut counts = HashMap::default();
+        for to in self.transactions().iter().filter_map(|tx| tx.kind().to().copied()) {
+            *counts.entry(to).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Returns a bloom filter over the recipients of the block's transactions. Contract creation
+    /// transactions are skipped.
+    ///
+    /// This is a heuristic prefilter for finding blocks with transactions to a set of addresses:
+    /// if the bloom doesn't contain an address, no transaction is sent to it, but false positives
+    /// are possible. It's unrelated to the consensus logs bloom of the header.
+    fn recipients_bloom(&self) -> Bloom {
+        let mut bloom = Bloom::ZERO;
+        for to in self.transactions().iter().filter_map(|tx| tx.kind().to().copied()) {
+            bloom.accrue(BloomInput::Raw(to.as_slice()));
+        }
+        bloom
+    }
+
+    /// Returns the effective gas price of every transaction in the block given the block's
+    /// `base_fee`, in transaction order.
+    ///
+    /// For dynamic fee transactions this is `min(max_fee_per_gas, base_fee +
+    /// max_priority_fee_per_gas)`, for legacy transactions it's the gas price.
+    fn effective_gas_prices(&self, base_fee: u64) -> Vec<u128> {
+        self.transactions().iter().map(|tx| tx.effective_gas_price(Some(base_fee))).collect()
+    }
+
+    /// Returns the total native token value transferred by the block's transactions, saturating
+    /// at [`U256::MAX`].
+    fn total_value_transferred(&self) -> U256 {
+        self.transactions().iter().fold(U256::ZERO, |total, tx| total.saturating_add(tx.value()))
+    }
+
+    /// Returns the total gas limit of the block's transactions grouped by transaction type.
+    ///
+    /// This only reflects the gas limits declared by transactions, since the actual gas used
+    /// requires execution results.
+    fn gas_limit_by_type(&self) -> BTreeMap<u8, u64> {
+        let mut gas_limits = BTreeMap::new();
+        for tx in self.transactions() {
+            *gas_limits.entry(tx.ty()).or_default() += tx.gas_limit();
+        }
+        gas_limits
+    }
+
+    /// Calculates the total blob gas used by the block's blob transactions, which is the number
+    /// of blob versioned hashes multiplied by [`DATA_GAS_PER_BLOB`].
+    ///
+    /// Returns `0` if the block contains no blob transactions.
+    fn calculate_blob_gas_used(&self) -> u64 {
+        self.transactions()
+            .iter()
+            .filter_map(|tx| tx.blob_versioned_hashes())
+            .map(|hashes| hashes.len() as u64 * DATA_GAS_PER_BLOB)
+            .sum()
+    }
+
+    /// Returns copies of all blob versioned hashes of the block body, in transaction order.
+    ///
+    /// The transactions are processed in parallel if there are at least
+    /// `PARALLEL_SENDER_RECOVERY_THRESHOLD` of them, see
+    /// [`Self::blob_versioned_hashes_copied_with_threshold`].
+    fn blob_versioned_hashes_copied(&self) -> Vec<B256> {
+        #[cfg(feature = "std")]
+        let threshold = *crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD;
+        #[cfg(not(feature = "std"))]
+        let threshold = usize::MAX;
+
+        self.blob_versioned_hashes_copied_with_threshold(threshold)
+    }
+
+    /// Returns copies of all blob versioned hashes of the block body, in transaction order,
+    /// processing the transactions in parallel if there are at least `threshold` of them.
+    ///
+    /// Without the `std` feature, the transactions are always processed sequentially.
+    fn blob_versioned_hashes_copied_with_threshold(&self, threshold: usize) -> Vec<B256> {
+        #[cfg(feature = "std")]
+        if self.transactions().len() >= threshold {
+            // Collecting a parallel iterator preserves the order of the transactions
+            return self
+                .transactions()
+                .par_iter()
+                .filter_map(|tx| tx.blob_versioned_hashes())
+                .flat_map_iter(|hashes| hashes.iter().copied())
+                .collect()
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = threshold;
+
+        self.transactions()
+            .iter()
+            .filter_map(|tx| tx.blob_versioned_hashes())
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    /// Calculates the header fields derived from the block body, iterating the transactions once
+    /// for the gas totals.
+    ///
+    /// The requests hash isn't included, because the requests are not part of the body.
+    fn header_fields(&self) -> BodyHeaderFields
+    where
+        Self::Transaction: Encodable2718,
+    {
+        let mut transactions_gas_limit = 0;
+        let mut blob_gas_used = 0;
+        for tx in self.transactions() {
+            transactions_gas_limit += tx.gas_limit();
+            blob_gas_used += tx
+                .blob_versioned_hashes()
+                .map_or(0, |hashes| hashes.len() as u64 * DATA_GAS_PER_BLOB);
+        }
+
+        let mut ommers_rlp = Vec::new();
+        alloy_rlp::encode_list(self.ommers(), &mut ommers_rlp);
+
+        BodyHeaderFields {
+            transactions_root: ordered_trie_root_with_encoder(self.transactions(), |tx, buf| {
+                tx.encode_2718(buf)
+            }),
+            ommers_hash: keccak256(ommers_rlp),
+            withdrawals_root: self.withdrawals_slice().map(ordered_trie_root),
+            transactions_gas_limit,
+            blob_gas_used,
+        }
+    }
+
+    /// Returns the Merkle-Patricia proof of inclusion of the transaction at `index` in the
+    /// transactions trie, or `None` if there is no transaction at `index`.
+    ///
+    /// The proof is the list of RLP encoded trie nodes from the root to the leaf keyed by the RLP
+    /// encoded `index`, and verifies against the transactions root of the block.
+    fn transaction_proof(&self, index: usize) -> Option<Vec<Bytes>>
+    where
+        Self::Transaction: Encodable2718,
+    {
+        let transactions = self.transactions();
+        if index >= transactions.len() {
+            return None
+        }
+
+        let target = Nibbles::unpack(alloy_rlp::encode(index));
+        let mut hash_builder =
+            HashBuilder::default().with_proof_retainer(ProofRetainer::from_iter([target]));
+        let mut value = Vec::new();
+        for i in 0..transactions.len() {
+            // Leaves are added in the order of their RLP encoded keys
+            let tx_index = adjust_index_for_rlp(i, transactions.len());
+            value.clear();
+            transactions[tx_index].encode_2718(&mut value);
+            hash_builder.add_leaf(Nibbles::unpack(alloy_rlp::encode(tx_index)), &value);
+        }
+        hash_builder.root();
+
+        Some(
+            hash_builder
+                .take_proof_nodes()
+                .into_nodes_sorted()
+                .into_iter()
+                .map(|(_, node)| node)
+                .collect(),
+        )
+    }
+
+    /// Returns the positions of the block's transactions within the block, grouped by transaction
+    /// type.
+    fn tx_indices_by_type(&self) -> BTreeMap<u8, Vec<usize>> {
+        let mut indices = BTreeMap::<u8, Vec<usize>>::new();
+        for (index, tx) in self.transactions().iter().enumerate() {
+            indices.entry(tx.ty()).or_default().push(index);
+        }
+        indices
+    }
+
+    /// Returns the transaction type shared by all of the block's transactions, or `None` if the
+    /// block contains transactions of different types.
+    ///
+    /// An empty block also returns `None`, since there is no type to specialize on.
+    fn single_transaction_type(&self) -> Option<u8> {
+        let (first, rest) = self.transactions().split_first()?;
+        let ty = first.ty();
+        rest.iter().all(|tx| tx.ty() == ty).then_some(ty)
+    }
+
+    /// Returns the index and the authorization list of every EIP-7702 transaction in the block,
+    /// in transaction order.
+    fn authorization_lists(&self) -> Vec<(usize, &[SignedAuthorization])> {
+        self.transactions()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tx)| tx.authorization_list().map(|list| (index, list)))
+            .collect()
+    }
+
+    /// Returns the index of the transaction with the given hash within the block, or `None` if
+    /// the block doesn't contain it.
+    ///
+    /// Signed transactions cache their hashes, so this is a linear scan without hashing and
+    /// doesn't benefit from a parallel search.
+    fn transaction_index_by_hash(&self, hash: B256) -> Option<usize>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        self.transactions().iter().position(|tx| *tx.tx_hash() == hash)
+    }
+
+    /// Returns an iterator over the block's transactions paired with their senders, recovering
+    /// each sender only when the iterator advances to its transaction.
+    ///
+    /// The sender is a [`RecoveryError`] if the transaction's signature is invalid. Recovery is
+    /// sequential, so this forgoes the parallel recovery of all senders in favor of laziness: it
+    /// suits execution paths that may exit before processing every transaction.
+    fn transactions_with_senders_lazy(
+        &self,
+    ) -> impl Iterator<Item = (Result<Address, RecoveryError>, &Self::Transaction)>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        self.transactions().iter().map(|tx| {
+            let sender =
+                tx.recover_signer().ok_or_else(|| RecoveryError { tx_hash: *tx.tx_hash() });
+            (sender, tx)
+        })
+    }
+
+    /// Recover signer addresses for all transactions in the block body, in parallel if there are at
+    /// least `threshold` transactions.
+    ///
+    /// Returns `None` if some transaction's signature is invalid. Pass [`usize::MAX`] to always
+    /// recover sequentially, e.g. when the rayon thread pool is already busy with other work.
+    /// Without the `std` feature, the senders are always recovered sequentially.
+    fn recover_signers_with_threshold(&self, threshold: usize) -> Option<Vec<Address>>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        #[cfg(feature = "std")]
+        if self.transactions().len() >= threshold {
+            return self.transactions().par_iter().map(|tx| tx.recover_signer()).collect()
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = threshold;
+
+        self.transactions().iter().map(|tx| tx.recover_signer()).collect()
+    }
+
+    /// Recover signer addresses for all transactions in the block body into the provided buffer,
+    /// reusing its allocation.
+    ///
+    /// The buffer is cleared before recovering. Returns `false` if some transaction's signature is
+    /// invalid, leaving the buffer cleared.
+    fn recover_signers_into(&self, out: &mut Vec<Address>) -> bool
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        out.clear();
+
+        #[cfg(feature = "std")]
+        if self.transactions().len() >= *crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD {
+            out.resize(self.transactions().len(), Address::ZERO);
+            let recovered =
+                out.par_iter_mut().zip(self.transactions()).try_for_each(|(signer, tx)| {
+                    *signer = tx.recover_signer()?;
+                    Some(())
+                });
+            if recovered.is_none() {
+                out.clear();
+                return false
+            }
+            return true
+        }
+
+        for tx in self.transactions() {
+            let Some(signer) = tx.recover_signer() else {
+                out.clear();
+                return false
+            };
+            out.push(signer);
+        }
+        true
+    }
+
+    /// Recover signer addresses for all transactions in the block body _without ensuring that the
+    /// signatures have a low `s` value_, in parallel if there are at least
+    /// `PARALLEL_SENDER_RECOVERY_THRESHOLD` transactions.
+    ///
+    /// Only use for the transactions that were already validated, e.g. the blocks stored in the
+    /// database. Returns `None` if some transaction's signature is invalid, like
+    /// [`Self::recover_signers_with_threshold`].
+    fn recover_signers_unchecked(&self) -> Option<Vec<Address>>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        #[cfg(feature = "std")]
+        if self.transactions().len() >= *crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD {
+            return self.transactions().par_iter().map(|tx| tx.recover_signer_unchecked()).collect()
+        }
+
+        self.transactions().iter().map(|tx| tx.recover_signer_unchecked()).collect()
+    }
+
+    /// Ensures that the nonces of each sender's transactions increase by exactly one in block
+    /// order, starting from the nonce of the sender's first transaction.
+    ///
+    /// Recovers the sender of every transaction. Returns an error for the first transaction whose
+    /// sender can't be recovered or whose nonce doesn't follow its sender's previous transaction.
+    fn validate_sender_nonce_ordering(&self) -> Result<(), BodyValidationError>
+    where
+        Self::Transaction: SignedTransaction,
+    {
+        let mut next_nonces = BTreeMap::<Address, u64>::new();
+        for (index, (sender, tx)) in self.transactions_with_senders_lazy().enumerate() {
+            let sender = sender.map_err(|_| BodyValidationError::TransactionSignature { index })?;
+            let nonce = tx.nonce();
+            if let Some(&expected) = next_nonces.get(&sender) {
+                if nonce != expected {
+                    return Err(BodyValidationError::SenderNonce {
+                        index,
+                        sender,
+                        nonce: GotExpected { got: nonce, expected },
+                    })
+                }
+            }
+            next_nonces.insert(sender, nonce.saturating_add(1));
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the block body is empty in the consensus sense: there are no
+    /// transactions, no ommers, and no withdrawals.
+    ///
+    /// The transactions, ommers and withdrawals roots of such a block are all equal to the
+    /// well-known empty roots, so they don't need to be calculated.
+    fn is_consensus_empty(&self) -> bool {
+        self.transactions().is_empty() &&
+            self.ommers().is_empty() &&
+            self.withdrawals().map_or(true, |withdrawals| withdrawals.is_empty())
+    }
+
+    /// Ensures that the number of ommers in the block doesn't exceed `max`.
+    ///
+    /// This is a cheap structural check that can be performed before calculating the ommers root.
+    /// Ethereum allows at most [`MAXIMUM_OMMERS`](crate::constants::MAXIMUM_OMMERS) ommers
+    /// before the merge and none after it, in which case `max` should be `0`.
+    fn validate_ommers_count(&self, max: usize) -> Result<(), BodyValidationError> {
+        let count = self.ommers().len();
+        if count > max {
+            return Err(BodyValidationError::OmmersCount(GotExpected { got: count, expected: max }))
+        }
+        Ok(())
+    }
+
+    /// Ensures that the ommers in the block are distinct from each other and from the block itself,
+    /// identified by `own_hash`.
+    ///
+    /// Each ommer is hashed to compare it. Returns an error for the first ommer that is the block
+    /// itself or a duplicate of a preceding ommer.
+    fn validate_ommers_distinct(&self, own_hash: B256) -> Result<(), BodyValidationError> {
+        let mut hashes = BTreeSet::new();
+        for (index, ommer) in self.ommers().iter().enumerate() {
+            let hash = ommer.hash_slow();
+            if hash == own_hash {
+                return Err(BodyValidationError::SelfOmmer { index })
+            }
+            if !hashes.insert(hash) {
+                return Err(BodyValidationError::DuplicateOmmer { index, hash })
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that all transactions in the block are signed for the `expected` chain id.
+    ///
+    /// Legacy transactions without a chain id (pre-EIP-155) are allowed. Returns an error for the
+    /// first transaction with a different chain id.
+    fn validate_chain_ids(&self, expected: u64) -> Result<(), BodyValidationError> {
+        for (index, tx) in self.transactions().iter().enumerate() {
+            if let Some(chain_id) = tx.chain_id() {
+                if chain_id != expected {
+                    return Err(BodyValidationError::TransactionChainId {
+                        index,
+                        chain_id: GotExpected { got: chain_id, expected },
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that the withdrawals in the block start at `expected_first_index` and that their
+    /// indices increase by one without gaps.
+    ///
+    /// Returns an error for the first withdrawal with an unexpected index. Bodies without
+    /// withdrawals are always valid.
+    fn validate_withdrawal_indices(
+        &self,
+        expected_first_index: u64,
+    ) -> Result<(), BodyValidationError> {
+        let Some(withdrawals) = self.withdrawals_slice() else { return Ok(()) };
+        for (position, withdrawal) in withdrawals.iter().enumerate() {
+            let expected = expected_first_index + position as u64;
+            if withdrawal.index != expected {
+                return Err(BodyValidationError::WithdrawalIndex {
+                    position,
+                    index: GotExpected { got: withdrawal.index, expected },
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures that blob transactions in the block only reference blobs by their versioned hashes
+    /// and don't carry the blob sidecar inline.
+    ///
+    /// Blob payloads are only propagated in the network encoding of a transaction, and must be
+    /// stripped from the consensus encoding included in the block. Returns an error for the first
+    /// blob transaction with a sidecar.
+    fn validate_no_inline_blobs(&self) -> Result<(), BodyValidationError>
+    where
+        Self::Transaction: MaybeBlobSidecar,
+    {
+        validate_transactions_no_inline_blobs(self.transactions())
+    }
+
+    /// Ensures that the transactions root calculated from the block body matches `expected`, e.g.
+    /// the transactions root of the header.
+    fn validate_transaction_root(&self, expected: B256) -> Result<(), BodyValidationError>
+    where
+        Self::Transaction: Encodable2718,
+    {
+        let transactions_root =
+            ordered_trie_root_with_encoder(self.transactions(), |tx, buf| tx.encode_2718(buf));
+        if transactions_root != expected {
+            return Err(BodyValidationError::TransactionsRoot(GotExpected {
+                got: transactions_root,
+                expected,
+            }))
+        }
+        Ok(())
+    }
+
+    /// Ensures that the ommers hash calculated from the block body matches `expected`, e.g. the
+    /// ommers hash of the header.
+    fn validate_ommers_root(&self, expected: B256) -> Result<(), BodyValidationError> {
+        let mut ommers_rlp = Vec::new();
+        alloy_rlp::encode_list(self.ommers(), &mut ommers_rlp);
+        let ommers_hash = keccak256(ommers_rlp);
+        if ommers_hash != expected {
+            return Err(BodyValidationError::OmmersHash(GotExpected { got: ommers_hash, expected }))
+        }
+        Ok(())
+    }
+
+    /// Ensures that the withdrawals root calculated from the block body matches `expected`, e.g.
+    /// the withdrawals root of the header.
+    ///
+    /// Withdrawals are expected in the block body if and only if `expected` is set.
+    fn validate_withdrawals_root(&self, expected: Option<B256>) -> Result<(), BodyValidationError> {
+        match (self.withdrawals_slice().map(ordered_trie_root), expected) {
+            (Some(withdrawals_root), Some(expected)) if withdrawals_root != expected => {
+                Err(BodyValidationError::WithdrawalsRoot(GotExpected {
+                    got: withdrawals_root,
+                    expected,
+                }))
+            }
+            (Some(_), None) => Err(BodyValidationError::UnexpectedWithdrawals),
+            (None, Some(_)) => Err(BodyValidationError::MissingWithdrawals),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Ensures that none of the `transactions` carries the blob sidecar inline, see
+/// [`BlockBody::validate_no_inline_blobs`].
+///
+/// Returns an error with the index of the first transaction with a sidecar.
+pub fn validate_transactions_no_inline_blobs<T: MaybeBlobSidecar>(
+    transactions: &[T],
+) -> Result<(), BodyValidationError> {
+    match transactions.iter().position(MaybeBlobSidecar::has_blob_sidecar) {
+        Some(index) => Err(BodyValidationError::InlineBlobs { index }),
+        None => Ok(()),
+    }
+}
+
+/// Ensures that the `body` belongs to the `header`, which is a child of `parent`.
+///
+/// The chain specific parameters are passed by the caller, e.g. from the chain spec at the block's
+/// timestamp.
+///
+/// Checks that:
+///  * the gas limit of all transactions doesn't exceed the block gas limit,
+///  * the number of blobs doesn't exceed `max_blob_count`,
+///  * the transactions root, ommers hash and withdrawals root match the header, see
+///    [`validate_body_roots`],
+///  * the base fee of the header follows from the parent, using the `base_fee_params`. Skipped if
+///    the parent has no base fee (pre-London).
+pub fn validate_body_in_context<B, H>(
+    body: &B,
+    header: &H,
+    parent: &H,
+    base_fee_params: BaseFeeParams,
+    max_blob_count: usize,
+) -> Result<(), BodyValidationError>
+where
+    B: BlockBody<Transaction: Encodable2718>,
+    H: BlockHeader,
+{
+    let gas_limit = body.transactions().iter().map(|tx| tx.gas_limit()).sum::<u64>();
+    if gas_limit > header.gas_limit() {
+        return Err(BodyValidationError::TransactionsGasLimit(GotExpected {
+            got: gas_limit,
+            expected: header.gas_limit(),
+        }))
+    }
+
+    let blob_count = body
+        .transactions()
+        .iter()
+        .filter_map(|tx| tx.blob_versioned_hashes())
+        .map(|hashes| hashes.len())
+        .sum::<usize>();
+    if blob_count > max_blob_count {
+        return Err(BodyValidationError::BlobCount(GotExpected {
+            got: blob_count,
+            expected: max_blob_count,
+        }))
+    }
+
+    validate_body_roots(body, header)?;
+
+    if let Some(parent_base_fee) = parent.base_fee_per_gas() {
+        let expected = base_fee_params.next_block_base_fee(
+            parent.gas_used(),
+            parent.gas_limit(),
+            parent_base_fee,
+        );
+        let base_fee = header.base_fee_per_gas().unwrap_or_default();
+        if base_fee != expected {
+            return Err(BodyValidationError::BaseFee(GotExpected { got: base_fee, expected }))
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures that the transactions root, ommers hash and withdrawals root calculated from the `body`
+/// match the `header`.
+pub fn validate_body_roots<B, H>(body: &B, header: &H) -> Result<(), BodyValidationError>
+where
+    B: BlockBody<Transaction: Encodable2718>,
+    H: BlockHeader,
+{
+    body.validate_transaction_root(header.transactions_root())?;
+    body.validate_ommers_root(header.ommers_hash())?;
+    body.validate_withdrawals_root(header.withdrawals_root())
 }
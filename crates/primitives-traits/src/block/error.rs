@@ -0,0 +1,89 @@
+//! Block body validation errors.
+
+use alloy_primitives::{Address, B256};
+
+use crate::GotExpected;
+
+/// Errors that can occur during structural checks of a block body.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+pub enum BodyValidationError {
+    /// The block body contains more ommers than allowed.
+    #[display("too many ommers: {_0}")]
+    OmmersCount(#[error(not(source))] GotExpected<usize>),
+    /// An ommer in the block body is the block's own header.
+    #[display("ommer at index {index} is the block itself")]
+    SelfOmmer {
+        /// Index of the ommer in the block body.
+        index: usize,
+    },
+    /// An ommer in the block body is a duplicate of a preceding ommer.
+    #[display("duplicate ommer {hash} at index {index}")]
+    DuplicateOmmer {
+        /// Index of the duplicate ommer in the block body.
+        index: usize,
+        /// Hash of the duplicate ommer.
+        hash: B256,
+    },
+    /// A withdrawal in the block body has an unexpected index.
+    #[display("unexpected index of withdrawal at position {position}: {index}")]
+    WithdrawalIndex {
+        /// Position of the withdrawal in the block body.
+        position: usize,
+        /// The actual and expected withdrawal index.
+        index: GotExpected<u64>,
+    },
+    /// A transaction in the block body has an unexpected chain id.
+    #[display("unexpected chain id of transaction at index {index}: {chain_id}")]
+    TransactionChainId {
+        /// Index of the transaction in the block body.
+        index: usize,
+        /// The actual and expected chain id.
+        chain_id: GotExpected<u64>,
+    },
+    /// The sender of a transaction in the block body can't be recovered from its signature.
+    #[display("invalid signature of transaction at index {index}")]
+    TransactionSignature {
+        /// Index of the transaction in the block body.
+        index: usize,
+    },
+    /// A transaction in the block body doesn't follow the previous nonce of its sender.
+    #[display("unexpected nonce of transaction at index {index} from {sender}: {nonce}")]
+    SenderNonce {
+        /// Index of the transaction in the block body.
+        index: usize,
+        /// Sender of the transaction.
+        sender: Address,
+        /// The actual and expected nonce.
+        nonce: GotExpected<u64>,
+    },
+    /// The total gas limit of the transactions exceeds the block gas limit.
+    #[display("transactions gas limit {_0}")]
+    TransactionsGasLimit(#[error(not(source))] GotExpected<u64>),
+    /// The block body contains more blobs than allowed.
+    #[display("too many blobs: {_0}")]
+    BlobCount(#[error(not(source))] GotExpected<usize>),
+    /// The transactions root of the block body doesn't match the header.
+    #[display("mismatched transactions root: {_0}")]
+    TransactionsRoot(#[error(not(source))] GotExpected<B256>),
+    /// The ommers hash of the block body doesn't match the header.
+    #[display("mismatched ommers hash: {_0}")]
+    OmmersHash(#[error(not(source))] GotExpected<B256>),
+    /// The withdrawals root of the block body doesn't match the header.
+    #[display("mismatched withdrawals root: {_0}")]
+    WithdrawalsRoot(#[error(not(source))] GotExpected<B256>),
+    /// The block body contains withdrawals, but the header has no withdrawals root.
+    #[display("unexpected withdrawals")]
+    UnexpectedWithdrawals,
+    /// The header has a withdrawals root, but the block body contains no withdrawals.
+    #[display("missing withdrawals")]
+    MissingWithdrawals,
+    /// A blob transaction in the block body carries the blob sidecar inline.
+    #[display("blob transaction at index {index} carries blob data inline")]
+    InlineBlobs {
+        /// Index of the transaction in the block body.
+        index: usize,
+    },
+    /// The base fee of the header doesn't follow from the parent.
+    #[display("mismatched base fee: {_0}")]
+    BaseFee(#[error(not(source))] GotExpected<u64>),
+}
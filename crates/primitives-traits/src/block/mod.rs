@@ -1,6 +1,7 @@
 //! Block abstraction.
 
 pub mod body;
+pub mod error;
 pub mod header;
 
 use alloc::fmt;
@@ -26,8 +26,9 @@ pub use receipt::{FullReceipt, Receipt};
 
 pub mod transaction;
 pub use transaction::{
+    blob::MaybeBlobSidecar,
     execute::FillTxEnv,
-    signed::{FullSignedTx, SignedTransaction},
+    signed::{FullSignedTx, RecoveryError, SignedTransaction},
     FullTransaction, Transaction, TransactionExt,
 };
 
@@ -36,7 +37,11 @@ pub use integer_list::{IntegerList, IntegerListError};
 
 pub mod block;
 pub use block::{
-    body::{BlockBody, FullBlockBody},
+    body::{
+        validate_body_in_context, validate_body_roots, validate_transactions_no_inline_blobs,
+        BlockBody, BodyHeaderFields, FullBlockBody,
+    },
+    error::BodyValidationError,
     header::{BlockHeader, FullBlockHeader},
     Block, FullBlock,
 };
@@ -80,7 +85,7 @@ pub use size::InMemorySize;
 
 /// Node traits
 pub mod node;
-pub use node::{FullNodePrimitives, NodePrimitives, ReceiptTy};
+pub use node::{FullNodePrimitives, HeaderTy, NodePrimitives, ReceiptTy};
 
 /// Helper trait that requires arbitrary implementation if the feature is enabled.
 #[cfg(any(feature = "test-utils", feature = "arbitrary"))]
@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::{BlockBody, FullBlock, FullReceipt, FullSignedTx, FullTxType, MaybeSerde};
+use crate::{Block, BlockBody, FullBlock, FullReceipt, FullSignedTx, FullTxType, MaybeSerde};
 
 /// Configures all the primitive types of the node.
 pub trait NodePrimitives:
@@ -17,6 +17,17 @@ pub trait NodePrimitives:
         + Eq
         + MaybeSerde
         + 'static;
+    /// Block header primitive.
+    type BlockHeader: Send
+        + Sync
+        + Unpin
+        + Clone
+        + Default
+        + fmt::Debug
+        + PartialEq
+        + Eq
+        + MaybeSerde
+        + 'static;
     /// Signed version of the transaction type.
     type SignedTx: Send
         + Sync
@@ -45,6 +56,7 @@ pub trait NodePrimitives:
 
 impl NodePrimitives for () {
     type Block = ();
+    type BlockHeader = ();
     type SignedTx = ();
     type TxType = ();
     type Receipt = ();
@@ -69,10 +81,14 @@ where
     T: FullNodePrimitives<Block: 'static, SignedTx: 'static, Receipt: 'static, TxType: 'static>,
 {
     type Block = T::Block;
+    type BlockHeader = <T::Block as Block>::Header;
     type SignedTx = T::SignedTx;
     type TxType = T::TxType;
     type Receipt = T::Receipt;
 }
 
+/// Helper adapter type for accessing [`NodePrimitives`] block header type.
+pub type HeaderTy<N> = <N as NodePrimitives>::BlockHeader;
+
 /// Helper adapter type for accessing [`NodePrimitives`] receipt type.
 pub type ReceiptTy<N> = <N as NodePrimitives>::Receipt;
@@ -0,0 +1,11 @@
+//! Abstraction of a transaction that may carry blob data.
+
+/// A transaction that may carry the sidecar of an EIP-4844 blob transaction, i.e. the blobs with
+/// their commitments and proofs, in addition to the versioned hashes of the blobs.
+///
+/// The sidecar is only included in the network form of a blob transaction, and must be stripped
+/// from the consensus form that is included in blocks.
+pub trait MaybeBlobSidecar {
+    /// Returns `true` if the transaction is a blob transaction with the blob sidecar attached.
+    fn has_blob_sidecar(&self) -> bool;
+}
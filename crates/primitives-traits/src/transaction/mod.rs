@@ -1,5 +1,6 @@
 //! Transaction abstraction
 
+pub mod blob;
 pub mod execute;
 pub mod signed;
 
@@ -10,6 +11,16 @@ use reth_codecs::Compact;
 
 use crate::{FullTxType, InMemorySize, MaybeArbitrary, MaybeSerde, TxType};
 
+/// Expected number of transactions where we can expect a speed-up by recovering the senders in
+/// parallel.
+#[cfg(feature = "std")]
+pub static PARALLEL_SENDER_RECOVERY_THRESHOLD: std::sync::LazyLock<usize> =
+    std::sync::LazyLock::new(|| match rayon::current_num_threads() {
+        0..=1 => usize::MAX,
+        2..=8 => 10,
+        _ => 5,
+    });
+
 /// Helper trait that unifies all behaviour required by transaction to support full node operations.
 pub trait FullTransaction: Transaction<Type: FullTxType> + Compact {}
 
@@ -20,6 +20,14 @@ impl<T> FullSignedTx for T where
 {
 }
 
+/// Error returned when the sender of a signed transaction can't be recovered from its signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+#[display("failed to recover the sender of transaction {tx_hash}")]
+pub struct RecoveryError {
+    /// Hash of the transaction.
+    pub tx_hash: TxHash,
+}
+
 /// A signed transaction.
 #[auto_impl::auto_impl(&, Arc)]
 pub trait SignedTransaction:
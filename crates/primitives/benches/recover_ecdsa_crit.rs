@@ -3,24 +3,42 @@ use alloy_primitives::hex_literal::hex;
 use alloy_rlp::Decodable;
 use criterion::{criterion_group, criterion_main, Criterion};
 use pprof::criterion::{Output, PProfProfiler};
-use reth_primitives::TransactionSigned;
+use reth_primitives::{BlockBody, TransactionSigned};
+use reth_primitives_traits::BlockBody as _;
+
+const RAW_TX: [u8; 141] = hex!("f88b8212b085028fa6ae00830f424094aad593da0c8116ef7d2d594dd6a63241bccfc26c80a48318b64b000000000000000000000000641c5d790f862a58ec7abcfd644c0442e9c201b32aa0a6ef9e170bca5ffb7ac05433b13b7043de667fbb0b4a5e45d3b54fb2d6efcc63a0037ec2c05c3d60c5f5f78244ce0a3859e3a18a36c61efb061b383507d3ce19d2");
 
 /// Benchmarks the recovery of the public key from the ECDSA message using criterion.
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("recover ECDSA", |b| {
         b.iter(|| {
-            let raw =hex!("f88b8212b085028fa6ae00830f424094aad593da0c8116ef7d2d594dd6a63241bccfc26c80a48318b64b000000000000000000000000641c5d790f862a58ec7abcfd644c0442e9c201b32aa0a6ef9e170bca5ffb7ac05433b13b7043de667fbb0b4a5e45d3b54fb2d6efcc63a0037ec2c05c3d60c5f5f78244ce0a3859e3a18a36c61efb061b383507d3ce19d2");
-            let mut pointer = raw.as_ref();
+            let mut pointer = RAW_TX.as_ref();
             let tx = TransactionSigned::decode(&mut pointer).unwrap();
             tx.recover_signer();
-            }
-        )
+        })
     });
 }
 
+/// Benchmarks the sequential and parallel recovery of the block body senders, to compare against
+/// the default parallel recovery threshold.
+pub fn recover_signers_benchmark(c: &mut Criterion) {
+    let tx = TransactionSigned::decode(&mut RAW_TX.as_ref()).unwrap();
+    let mut group = c.benchmark_group("recover signers");
+    for count in [2, 8, 32, 128] {
+        let body = BlockBody { transactions: vec![tx.clone(); count], ..Default::default() };
+        group.bench_function(format!("sequential {count}"), |b| {
+            b.iter(|| body.recover_signers_with_threshold(usize::MAX))
+        });
+        group.bench_function(format!("parallel {count}"), |b| {
+            b.iter(|| body.recover_signers_with_threshold(0))
+        });
+    }
+    group.finish();
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
-    targets = criterion_benchmark
+    targets = criterion_benchmark, recover_signers_benchmark
 }
 criterion_main!(benches);
@@ -1,4 +1,6 @@
-use crate::{GotExpected, SealedHeader, TransactionSigned, TransactionSignedEcRecovered};
+use crate::{
+    BodyValidationError, GotExpected, SealedHeader, TransactionSigned, TransactionSignedEcRecovered,
+};
 use alloc::vec::Vec;
 use alloy_consensus::Header;
 use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawals};
@@ -302,6 +304,17 @@ impl<H, B> SealedBlock<H, B> {
     }
 }
 
+/// Combines the `header` and `body` into a [`SealedBlock`], after validating that the roots of the
+/// header match the body, see [`validate_body_roots`](reth_primitives_traits::validate_body_roots).
+pub fn seal_block<H, B>(header: H, body: B) -> Result<SealedBlock<H, B>, BodyValidationError>
+where
+    H: reth_primitives_traits::BlockHeader,
+    B: reth_primitives_traits::BlockBody<Transaction: Encodable2718>,
+{
+    reth_primitives_traits::validate_body_roots(&body, &header)?;
+    Ok(SealedBlock::new(SealedHeader::seal(header), body))
+}
+
 impl SealedBlock {
     /// Splits the sealed block into underlying components
     #[inline]
@@ -612,8 +625,17 @@ impl BlockBody {
     }
 
     /// Recover signer addresses for all transactions in the block body.
+    ///
+    /// Recovers in parallel if there are at least [`PARALLEL_SENDER_RECOVERY_THRESHOLD`]
+    /// transactions, see [`BlockBody::recover_signers_with_threshold`] for a custom cutoff.
+    ///
+    /// [`PARALLEL_SENDER_RECOVERY_THRESHOLD`]: crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD
+    /// [`BlockBody::recover_signers_with_threshold`]: reth_primitives_traits::BlockBody::recover_signers_with_threshold
     pub fn recover_signers(&self) -> Option<Vec<Address>> {
-        TransactionSigned::recover_signers(&self.transactions, self.transactions.len())
+        reth_primitives_traits::BlockBody::recover_signers_with_threshold(
+            self,
+            *crate::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD,
+        )
     }
 
     /// Returns whether or not the block body contains any blob transactions.
@@ -628,6 +650,18 @@ impl BlockBody {
         self.transactions.iter().any(|tx| tx.is_eip7702())
     }
 
+    /// Returns an iterator over all EIP-7702 transactions of the block
+    #[inline]
+    pub fn eip7702_transactions_iter(&self) -> impl Iterator<Item = &TransactionSigned> + '_ {
+        self.transactions.iter().filter(|tx| tx.is_eip7702())
+    }
+
+    /// Returns only the EIP-7702 transactions, if any, from the block body.
+    #[inline]
+    pub fn eip7702_transactions(&self) -> Vec<&TransactionSigned> {
+        self.eip7702_transactions_iter().collect()
+    }
+
     /// Returns an iterator over all blob transactions of the block
     #[inline]
     pub fn blob_transactions_iter(&self) -> impl Iterator<Item = &TransactionSigned> + '_ {
@@ -654,6 +688,16 @@ impl BlockBody {
         self.blob_versioned_hashes_iter().collect()
     }
 
+    /// Returns the hash of every blob transaction in the block body together with the blob
+    /// versioned hashes it carries, in transaction order.
+    pub fn blob_versioned_hashes_by_tx(&self) -> Vec<(B256, Vec<B256>)> {
+        self.blob_transactions_iter()
+            .filter_map(|tx| {
+                tx.as_eip4844().map(|blob_tx| (tx.hash(), blob_tx.blob_versioned_hashes.clone()))
+            })
+            .collect()
+    }
+
     /// Returns an iterator over all transactions.
     #[inline]
     pub fn transactions(&self) -> impl Iterator<Item = &TransactionSigned> + '_ {
@@ -677,10 +721,19 @@ impl InMemorySize for BlockBody {
 
 impl reth_primitives_traits::BlockBody for BlockBody {
     type Transaction = TransactionSigned;
+    type OmmerHeader = Header;
 
     fn transactions(&self) -> &[Self::Transaction] {
         &self.transactions
     }
+
+    fn ommers(&self) -> &[Self::OmmerHeader] {
+        &self.ommers
+    }
+
+    fn withdrawals(&self) -> Option<&Withdrawals> {
+        self.withdrawals.as_ref()
+    }
 }
 
 impl From<Block> for BlockBody {
@@ -983,6 +1036,14 @@ mod tests {
         assert_block::<SealedBlock>();
     }
 
+    /// Signs the transaction with the test signature.
+    fn test_signed_tx(tx: crate::Transaction) -> TransactionSigned {
+        TransactionSigned::from_transaction_and_signature(
+            tx,
+            alloy_primitives::PrimitiveSignature::test_signature(),
+        )
+    }
+
     /// Check parsing according to EIP-1898.
     #[test]
     fn can_parse_blockid_u64() {
@@ -1170,4 +1231,985 @@ mod tests {
         let decoded = BlockBody::decode(&mut buf.as_slice()).unwrap();
         assert_eq!(body, decoded);
     }
+
+    #[test]
+    fn is_consensus_empty() {
+        use alloy_eips::eip4895::Withdrawal;
+        use reth_primitives_traits::BlockBody as _;
+
+        let mut body = BlockBody::default();
+        assert!(body.is_consensus_empty());
+
+        // Empty withdrawals after Shanghai
+        body.withdrawals = Some(Withdrawals::default());
+        assert!(body.is_consensus_empty());
+
+        body.withdrawals = Some(Withdrawals::new(vec![Withdrawal::default()]));
+        assert!(!body.is_consensus_empty());
+
+        let body = BlockBody { ommers: vec![Header::default()], ..Default::default() };
+        assert!(!body.is_consensus_empty());
+
+        let body =
+            BlockBody { transactions: vec![TransactionSigned::default()], ..Default::default() };
+        assert!(!body.is_consensus_empty());
+    }
+
+    #[test]
+    fn validate_ommers_count() {
+        use crate::BodyValidationError;
+        use reth_primitives_traits::{constants::MAXIMUM_OMMERS, BlockBody as _};
+
+        let mut body = BlockBody::default();
+        assert_eq!(body.validate_ommers_count(0), Ok(()));
+
+        body.ommers = vec![Header::default(); MAXIMUM_OMMERS];
+        assert_eq!(body.validate_ommers_count(MAXIMUM_OMMERS), Ok(()));
+
+        // Post-merge blocks must not contain any ommers
+        assert_eq!(
+            body.validate_ommers_count(0),
+            Err(BodyValidationError::OmmersCount(GotExpected { got: MAXIMUM_OMMERS, expected: 0 }))
+        );
+
+        body.ommers.push(Header::default());
+        assert_eq!(
+            body.validate_ommers_count(MAXIMUM_OMMERS),
+            Err(BodyValidationError::OmmersCount(GotExpected {
+                got: MAXIMUM_OMMERS + 1,
+                expected: MAXIMUM_OMMERS
+            }))
+        );
+    }
+
+    #[test]
+    fn validate_roots() {
+        use crate::{BodyValidationError, Transaction};
+        use alloy_consensus::TxLegacy;
+        use alloy_eips::eip4895::Withdrawal;
+        use reth_primitives_traits::BlockBody as _;
+
+        let body = BlockBody {
+            transactions: vec![test_signed_tx(Transaction::Legacy(TxLegacy::default()))],
+            ommers: vec![Header::default()],
+            withdrawals: Some(Withdrawals::new(vec![Withdrawal::default()])),
+        };
+
+        assert_eq!(body.validate_transaction_root(body.calculate_tx_root()), Ok(()));
+        assert_eq!(
+            body.validate_transaction_root(B256::ZERO),
+            Err(BodyValidationError::TransactionsRoot(GotExpected {
+                got: body.calculate_tx_root(),
+                expected: B256::ZERO
+            }))
+        );
+
+        assert_eq!(body.validate_ommers_root(body.calculate_ommers_root()), Ok(()));
+        assert_eq!(
+            body.validate_ommers_root(B256::ZERO),
+            Err(BodyValidationError::OmmersHash(GotExpected {
+                got: body.calculate_ommers_root(),
+                expected: B256::ZERO
+            }))
+        );
+
+        assert_eq!(body.validate_withdrawals_root(body.calculate_withdrawals_root()), Ok(()));
+        assert_eq!(
+            body.validate_withdrawals_root(Some(B256::ZERO)),
+            Err(BodyValidationError::WithdrawalsRoot(GotExpected {
+                got: body.calculate_withdrawals_root().unwrap(),
+                expected: B256::ZERO
+            }))
+        );
+        assert_eq!(
+            body.validate_withdrawals_root(None),
+            Err(BodyValidationError::UnexpectedWithdrawals)
+        );
+        assert_eq!(
+            BlockBody::default().validate_withdrawals_root(Some(B256::ZERO)),
+            Err(BodyValidationError::MissingWithdrawals)
+        );
+    }
+
+    #[test]
+    fn validate_withdrawal_indices() {
+        use crate::BodyValidationError;
+        use alloy_eips::eip4895::Withdrawal;
+        use reth_primitives_traits::BlockBody as _;
+
+        let withdrawals = |indices: &[u64]| {
+            Some(Withdrawals::new(
+                indices.iter().map(|&index| Withdrawal { index, ..Default::default() }).collect(),
+            ))
+        };
+
+        // Bodies without withdrawals are always valid
+        let mut body = BlockBody::default();
+        assert_eq!(body.validate_withdrawal_indices(5), Ok(()));
+
+        body.withdrawals = withdrawals(&[5, 6, 7]);
+        assert_eq!(body.validate_withdrawal_indices(5), Ok(()));
+
+        // Withdrawals don't start at the expected index
+        assert_eq!(
+            body.validate_withdrawal_indices(4),
+            Err(BodyValidationError::WithdrawalIndex {
+                position: 0,
+                index: GotExpected { got: 5, expected: 4 }
+            })
+        );
+
+        // Gap between withdrawal indices
+        body.withdrawals = withdrawals(&[5, 6, 8, 9]);
+        assert_eq!(
+            body.validate_withdrawal_indices(5),
+            Err(BodyValidationError::WithdrawalIndex {
+                position: 2,
+                index: GotExpected { got: 8, expected: 7 }
+            })
+        );
+
+        // Duplicate withdrawal index
+        body.withdrawals = withdrawals(&[5, 5]);
+        assert_eq!(
+            body.validate_withdrawal_indices(5),
+            Err(BodyValidationError::WithdrawalIndex {
+                position: 1,
+                index: GotExpected { got: 5, expected: 6 }
+            })
+        );
+    }
+
+    #[test]
+    fn blob_versioned_hashes_by_tx() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip4844};
+
+        let blob_hashes = [vec![B256::random(), B256::random()], vec![B256::random()]];
+        let transactions = [
+            Transaction::Eip4844(TxEip4844 {
+                blob_versioned_hashes: blob_hashes[0].clone(),
+                ..Default::default()
+            }),
+            Transaction::Eip1559(TxEip1559::default()),
+            Transaction::Eip4844(TxEip4844 {
+                nonce: 1,
+                blob_versioned_hashes: blob_hashes[1].clone(),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect::<Vec<_>>();
+        let body = BlockBody { transactions: transactions.clone(), ..Default::default() };
+
+        assert_eq!(
+            body.blob_versioned_hashes_by_tx(),
+            vec![
+                (transactions[0].hash(), blob_hashes[0].clone()),
+                (transactions[2].hash(), blob_hashes[1].clone()),
+            ]
+        );
+        assert!(BlockBody::default().blob_versioned_hashes_by_tx().is_empty());
+    }
+
+    #[test]
+    fn blob_versioned_hashes_copied() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip4844};
+        use reth_primitives_traits::BlockBody as _;
+
+        let transactions = (0..64u64)
+            .map(|nonce| {
+                let tx = if nonce % 3 == 0 {
+                    Transaction::Eip1559(TxEip1559 { nonce, ..Default::default() })
+                } else {
+                    Transaction::Eip4844(TxEip4844 {
+                        nonce,
+                        blob_versioned_hashes: (0..nonce % 7).map(|_| B256::random()).collect(),
+                        ..Default::default()
+                    })
+                };
+                test_signed_tx(tx)
+            })
+            .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        let expected = body.blob_versioned_hashes_iter().copied().collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        assert_eq!(body.blob_versioned_hashes_copied_with_threshold(usize::MAX), expected);
+        assert_eq!(body.blob_versioned_hashes_copied_with_threshold(0), expected);
+        assert_eq!(body.blob_versioned_hashes_copied(), expected);
+    }
+
+    #[test]
+    fn validate_ommers_distinct() {
+        use crate::BodyValidationError;
+        use reth_primitives_traits::BlockBody as _;
+
+        let ommer = |number| Header { number, ..Default::default() };
+        let own_hash = ommer(3).hash_slow();
+
+        let mut body = BlockBody { ommers: vec![ommer(1), ommer(2)], ..Default::default() };
+        assert_eq!(body.validate_ommers_distinct(own_hash), Ok(()));
+
+        body.ommers.push(ommer(1));
+        assert_eq!(
+            body.validate_ommers_distinct(own_hash),
+            Err(BodyValidationError::DuplicateOmmer { index: 2, hash: ommer(1).hash_slow() })
+        );
+
+        // The block itself is reported before the later duplicate
+        body.ommers.insert(1, ommer(3));
+        assert_eq!(
+            body.validate_ommers_distinct(own_hash),
+            Err(BodyValidationError::SelfOmmer { index: 1 })
+        );
+    }
+
+    #[test]
+    fn recipients_bloom() {
+        use crate::Transaction;
+        use alloy_consensus::TxLegacy;
+        use alloy_primitives::{Address, BloomInput, TxKind};
+        use reth_primitives_traits::BlockBody as _;
+
+        let recipient = Address::random();
+        let transactions = [TxKind::Call(recipient), TxKind::Create]
+            .into_iter()
+            .map(|to| test_signed_tx(Transaction::Legacy(TxLegacy { to, ..Default::default() })))
+            .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        let bloom = body.recipients_bloom();
+        assert!(bloom.contains_input(BloomInput::Raw(recipient.as_slice())));
+        assert!(!bloom.contains_input(BloomInput::Raw(Address::ZERO.as_slice())));
+        assert!(BlockBody::default().recipients_bloom().is_zero());
+    }
+
+    #[test]
+    fn validate_chain_ids() {
+        use crate::{BodyValidationError, Transaction};
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use reth_primitives_traits::BlockBody as _;
+
+        let body = |transactions: Vec<Transaction>| BlockBody {
+            transactions: transactions.into_iter().map(test_signed_tx).collect(),
+            ..Default::default()
+        };
+
+        assert_eq!(BlockBody::default().validate_chain_ids(1), Ok(()));
+
+        // Pre-EIP-155 legacy transactions don't have a chain id
+        let valid = body(vec![
+            Transaction::Legacy(TxLegacy { chain_id: Some(1), ..Default::default() }),
+            Transaction::Legacy(TxLegacy { chain_id: None, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { chain_id: 1, ..Default::default() }),
+        ]);
+        assert_eq!(valid.validate_chain_ids(1), Ok(()));
+
+        let invalid = body(vec![
+            Transaction::Legacy(TxLegacy { chain_id: None, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { chain_id: 1, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { chain_id: 10, ..Default::default() }),
+            Transaction::Legacy(TxLegacy { chain_id: Some(5), ..Default::default() }),
+        ]);
+        assert_eq!(
+            invalid.validate_chain_ids(1),
+            Err(BodyValidationError::TransactionChainId {
+                index: 2,
+                chain_id: GotExpected { got: 10, expected: 1 }
+            })
+        );
+    }
+
+    #[test]
+    fn transactions_to() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use alloy_primitives::TxKind;
+        use reth_primitives_traits::BlockBody as _;
+
+        let contract = Address::random();
+        let other = Address::random();
+        let transactions = [
+            Transaction::Legacy(TxLegacy { to: TxKind::Call(contract), ..Default::default() }),
+            Transaction::Legacy(TxLegacy { to: TxKind::Create, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { to: TxKind::Call(other), ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { to: TxKind::Call(contract), ..Default::default() }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect::<Vec<_>>();
+        let body = BlockBody { transactions: transactions.clone(), ..Default::default() };
+
+        assert_eq!(body.transactions_to(contract), vec![&transactions[0], &transactions[3]]);
+        assert_eq!(body.transactions_to(other), vec![&transactions[2]]);
+        assert!(body.transactions_to(Address::ZERO).is_empty());
+    }
+
+    #[test]
+    fn transactions_by_sender() {
+        use crate::{sign_message, Transaction};
+        use alloy_consensus::TxLegacy;
+        use reth_primitives_traits::BlockBody as _;
+
+        let transactions = [1, 2, 1]
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, key)| {
+                let tx =
+                    Transaction::Legacy(TxLegacy { nonce: nonce as u64, ..Default::default() });
+                let signature =
+                    sign_message(B256::with_last_byte(key), tx.signature_hash()).unwrap();
+                TransactionSigned::from_transaction_and_signature(tx, signature)
+            })
+            .collect::<Vec<_>>();
+        let body = BlockBody { transactions: transactions.clone(), ..Default::default() };
+
+        let sender = transactions[0].recover_signer().unwrap();
+        let other = transactions[1].recover_signer().unwrap();
+        assert_eq!(body.transactions_by_sender(sender), vec![&transactions[0], &transactions[2]]);
+        assert_eq!(body.transactions_by_sender(other), vec![&transactions[1]]);
+        assert!(body.transactions_by_sender(Address::ZERO).is_empty());
+    }
+
+    #[test]
+    fn recipient_counts() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use alloy_primitives::{map::HashMap, TxKind};
+        use reth_primitives_traits::BlockBody as _;
+
+        let router = Address::random();
+        let other = Address::random();
+        let transactions = [
+            Transaction::Legacy(TxLegacy { to: TxKind::Call(router), ..Default::default() }),
+            Transaction::Legacy(TxLegacy { to: TxKind::Create, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { to: TxKind::Call(other), ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { to: TxKind::Call(router), ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { to: TxKind::Call(router), ..Default::default() }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert_eq!(body.recipient_counts(), HashMap::from_iter([(router, 3), (other, 1)]));
+        assert!(BlockBody::default().recipient_counts().is_empty());
+    }
+
+    #[test]
+    fn effective_gas_prices() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use reth_primitives_traits::BlockBody as _;
+
+        let base_fee = 10;
+        let transactions = [
+            Transaction::Legacy(TxLegacy { gas_price: 15, ..Default::default() }),
+            // Priority fee is capped by the max fee
+            Transaction::Eip1559(TxEip1559 {
+                max_fee_per_gas: 12,
+                max_priority_fee_per_gas: 5,
+                ..Default::default()
+            }),
+            Transaction::Eip1559(TxEip1559 {
+                max_fee_per_gas: 20,
+                max_priority_fee_per_gas: 3,
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert_eq!(body.effective_gas_prices(base_fee), vec![15, 12, 13]);
+    }
+
+    #[test]
+    fn total_value_transferred() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use alloy_primitives::U256;
+        use reth_primitives_traits::BlockBody as _;
+
+        let body = |values: Vec<U256>| BlockBody {
+            transactions: values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let tx = if index % 2 == 0 {
+                        Transaction::Legacy(TxLegacy { value, ..Default::default() })
+                    } else {
+                        Transaction::Eip1559(TxEip1559 { value, ..Default::default() })
+                    };
+                    test_signed_tx(tx)
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            body(vec![U256::from(100), U256::ZERO, U256::from(20), U256::ZERO, U256::from(3)])
+                .total_value_transferred(),
+            U256::from(123)
+        );
+        assert_eq!(body(vec![U256::MAX, U256::from(1)]).total_value_transferred(), U256::MAX);
+        assert_eq!(BlockBody::default().total_value_transferred(), U256::ZERO);
+    }
+
+    #[test]
+    fn gas_limit_by_type() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip4844, TxLegacy};
+        use reth_primitives_traits::BlockBody as _;
+        use std::collections::BTreeMap;
+
+        let transactions = [
+            Transaction::Legacy(TxLegacy { gas_limit: 21_000, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { gas_limit: 50_000, ..Default::default() }),
+            Transaction::Eip4844(TxEip4844 { gas_limit: 100_000, ..Default::default() }),
+            Transaction::Legacy(TxLegacy { gas_limit: 30_000, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559 { gas_limit: 70_000, ..Default::default() }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert_eq!(
+            body.gas_limit_by_type(),
+            BTreeMap::from([(0, 51_000), (2, 120_000), (3, 100_000)])
+        );
+        assert!(BlockBody::default().gas_limit_by_type().is_empty());
+    }
+
+    #[test]
+    fn calculate_blob_gas_used() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip4844, TxLegacy};
+        use alloy_eips::eip4844::DATA_GAS_PER_BLOB;
+        use reth_primitives_traits::BlockBody as _;
+
+        let blob_tx = |blobs: usize| {
+            Transaction::Eip4844(TxEip4844 {
+                blob_versioned_hashes: vec![B256::random(); blobs],
+                ..Default::default()
+            })
+        };
+        let transactions = [blob_tx(2), Transaction::Legacy(TxLegacy::default()), blob_tx(1)]
+            .into_iter()
+            .map(test_signed_tx)
+            .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert_eq!(body.calculate_blob_gas_used(), 3 * DATA_GAS_PER_BLOB);
+        assert_eq!(BlockBody::default().calculate_blob_gas_used(), 0);
+    }
+
+    #[test]
+    fn header_fields() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip4844};
+        use alloy_eips::{eip4844::DATA_GAS_PER_BLOB, eip4895::Withdrawal};
+        use reth_primitives_traits::BlockBody as _;
+
+        let transactions = [
+            Transaction::Eip1559(TxEip1559 { gas_limit: 21_000, ..Default::default() }),
+            Transaction::Eip4844(TxEip4844 {
+                gas_limit: 50_000,
+                blob_versioned_hashes: vec![B256::random(), B256::random()],
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody {
+            transactions,
+            ommers: vec![Header::default()],
+            withdrawals: Some(Withdrawals::new(vec![Withdrawal::default()])),
+        };
+
+        let fields = body.header_fields();
+        assert_eq!(fields.transactions_root, body.calculate_tx_root());
+        assert_eq!(fields.ommers_hash, body.calculate_ommers_root());
+        assert_eq!(fields.withdrawals_root, body.calculate_withdrawals_root());
+        assert_eq!(fields.transactions_gas_limit, body.gas_limit_by_type().values().sum::<u64>());
+        assert_eq!(fields.blob_gas_used, 2 * DATA_GAS_PER_BLOB);
+
+        let empty = BlockBody::default().header_fields();
+        assert_eq!(empty.withdrawals_root, None);
+        assert_eq!(empty.blob_gas_used, 0);
+    }
+
+    #[test]
+    fn transaction_proof() {
+        use crate::{proofs::calculate_transaction_root, Transaction};
+        use alloy_consensus::TxLegacy;
+        use alloy_trie::{proof::verify_proof, Nibbles};
+        use reth_primitives_traits::BlockBody as _;
+
+        // Enough transactions for the trie to have branches on multiple levels
+        let transactions = (0..200)
+            .map(|nonce| {
+                test_signed_tx(Transaction::Legacy(TxLegacy { nonce, ..Default::default() }))
+            })
+            .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+        let root = calculate_transaction_root(&body.transactions);
+
+        for index in [0, 1, 127, 128, 199] {
+            let proof = body.transaction_proof(index).unwrap();
+            let key = Nibbles::unpack(alloy_rlp::encode(index));
+            let value = body.transactions[index].encoded_2718();
+            verify_proof(root, key, Some(value), &proof).unwrap();
+        }
+        assert_eq!(body.transaction_proof(200), None);
+    }
+
+    #[test]
+    fn tx_indices_by_type() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip4844, TxLegacy};
+        use reth_primitives_traits::BlockBody as _;
+        use std::collections::BTreeMap;
+
+        let transactions = [
+            Transaction::Legacy(TxLegacy::default()),
+            Transaction::Eip1559(TxEip1559::default()),
+            Transaction::Eip4844(TxEip4844::default()),
+            Transaction::Legacy(TxLegacy::default()),
+            Transaction::Eip1559(TxEip1559::default()),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert_eq!(
+            body.tx_indices_by_type(),
+            BTreeMap::from([(0, vec![0, 3]), (2, vec![1, 4]), (3, vec![2])])
+        );
+        assert!(BlockBody::default().tx_indices_by_type().is_empty());
+    }
+
+    #[test]
+    fn single_transaction_type() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxLegacy};
+        use reth_primitives_traits::BlockBody as _;
+
+        let body = |transactions: Vec<Transaction>| BlockBody {
+            transactions: transactions.into_iter().map(test_signed_tx).collect(),
+            ..Default::default()
+        };
+
+        let homogeneous = body(vec![
+            Transaction::Eip1559(TxEip1559::default()),
+            Transaction::Eip1559(TxEip1559::default()),
+        ]);
+        assert_eq!(homogeneous.single_transaction_type(), Some(2));
+
+        let mixed = body(vec![
+            Transaction::Legacy(TxLegacy::default()),
+            Transaction::Eip1559(TxEip1559::default()),
+        ]);
+        assert_eq!(mixed.single_transaction_type(), None);
+
+        assert_eq!(BlockBody::default().single_transaction_type(), None);
+    }
+
+    #[test]
+    fn authorization_lists() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip7702};
+        use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+        use alloy_primitives::U256;
+        use reth_primitives_traits::BlockBody as _;
+
+        let authorization_list = vec![SignedAuthorization::new_unchecked(
+            Authorization { chain_id: 1, address: Address::random(), nonce: 0 },
+            0,
+            U256::from(1),
+            U256::from(2),
+        )];
+        let transactions = [
+            Transaction::Eip1559(TxEip1559::default()),
+            Transaction::Eip7702(TxEip7702 {
+                authorization_list: authorization_list.clone(),
+                ..Default::default()
+            }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect();
+        let body = BlockBody { transactions, ..Default::default() };
+
+        assert!(body.has_eip7702_transactions());
+        assert_eq!(body.authorization_lists(), vec![(1, authorization_list.as_slice())]);
+        assert!(BlockBody::default().authorization_lists().is_empty());
+    }
+
+    #[test]
+    fn eip7702_transactions() {
+        use crate::Transaction;
+        use alloy_consensus::{TxEip1559, TxEip7702};
+
+        let transactions = [
+            Transaction::Eip7702(TxEip7702 { nonce: 0, ..Default::default() }),
+            Transaction::Eip1559(TxEip1559::default()),
+            Transaction::Eip7702(TxEip7702 { nonce: 1, ..Default::default() }),
+        ]
+        .into_iter()
+        .map(test_signed_tx)
+        .collect::<Vec<_>>();
+        let body = BlockBody { transactions: transactions.clone(), ..Default::default() };
+
+        assert_eq!(body.eip7702_transactions(), vec![&transactions[0], &transactions[2]]);
+        assert!(body.eip7702_transactions_iter().eq(body.eip7702_transactions()));
+        assert!(BlockBody::default().eip7702_transactions().is_empty());
+    }
+
+    #[test]
+    fn transaction_index_by_hash() {
+        use crate::Transaction;
+        use alloy_consensus::TxLegacy;
+        use reth_primitives_traits::BlockBody as _;
+
+        let transactions = (0..3)
+            .map(|nonce| {
+                test_signed_tx(Transaction::Legacy(TxLegacy { nonce, ..Default::default() }))
+            })
+            .collect::<Vec<_>>();
+        let body = BlockBody { transactions: transactions.clone(), ..Default::default() };
+
+        for (index, transaction) in transactions.iter().enumerate() {
+            assert_eq!(body.transaction_index_by_hash(transaction.hash()), Some(index));
+        }
+        assert_eq!(body.transaction_index_by_hash(B256::random()), None);
+    }
+
+    #[test]
+    fn validate_body_in_context() {
+        use crate::{
+            proofs::{
+                calculate_ommers_root, calculate_transaction_root, calculate_withdrawals_root,
+            },
+            BodyValidationError, Transaction,
+        };
+        use alloy_consensus::{TxEip1559, TxEip4844};
+        use alloy_eips::{
+            eip1559::BaseFeeParams, eip4844::MAX_BLOBS_PER_BLOCK, eip4895::Withdrawal,
+        };
+        use reth_primitives_traits::validate_body_in_context;
+
+        let ethereum = BaseFeeParams::ethereum();
+        let body = BlockBody {
+            transactions: vec![test_signed_tx(Transaction::Eip1559(TxEip1559 {
+                gas_limit: 21_000,
+                ..Default::default()
+            }))],
+            ommers: Vec::new(),
+            withdrawals: Some(Withdrawals::new(vec![Withdrawal::default()])),
+        };
+        let parent = Header {
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        let header = Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            transactions_root: calculate_transaction_root(&body.transactions),
+            ommers_hash: calculate_ommers_root(&body.ommers),
+            withdrawals_root: Some(calculate_withdrawals_root(body.withdrawals.as_ref().unwrap())),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_body_in_context(&body, &header, &parent, ethereum, MAX_BLOBS_PER_BLOCK),
+            Ok(())
+        );
+
+        // Transactions don't fit into the block gas limit
+        let small_header = Header { gas_limit: 20_000, ..header.clone() };
+        assert_eq!(
+            validate_body_in_context(&body, &small_header, &parent, ethereum, MAX_BLOBS_PER_BLOCK),
+            Err(BodyValidationError::TransactionsGasLimit(GotExpected {
+                got: 21_000,
+                expected: 20_000
+            }))
+        );
+
+        let blob_body = BlockBody {
+            transactions: vec![test_signed_tx(Transaction::Eip4844(TxEip4844 {
+                blob_versioned_hashes: vec![B256::ZERO; MAX_BLOBS_PER_BLOCK + 1],
+                ..Default::default()
+            }))],
+            ..body.clone()
+        };
+        assert_eq!(
+            validate_body_in_context(&blob_body, &header, &parent, ethereum, MAX_BLOBS_PER_BLOCK),
+            Err(BodyValidationError::BlobCount(GotExpected {
+                got: MAX_BLOBS_PER_BLOCK + 1,
+                expected: MAX_BLOBS_PER_BLOCK
+            }))
+        );
+
+        let other_header = Header { transactions_root: B256::ZERO, ..header.clone() };
+        assert_eq!(
+            validate_body_in_context(&body, &other_header, &parent, ethereum, MAX_BLOBS_PER_BLOCK),
+            Err(BodyValidationError::TransactionsRoot(GotExpected {
+                got: header.transactions_root,
+                expected: B256::ZERO
+            }))
+        );
+
+        let no_withdrawals = BlockBody { withdrawals: None, ..body.clone() };
+        assert_eq!(
+            validate_body_in_context(
+                &no_withdrawals,
+                &header,
+                &parent,
+                ethereum,
+                MAX_BLOBS_PER_BLOCK
+            ),
+            Err(BodyValidationError::MissingWithdrawals)
+        );
+
+        // Full parent block raises the base fee by 12.5%
+        let full_parent = Header { gas_used: 30_000_000, ..parent.clone() };
+        assert_eq!(
+            validate_body_in_context(&body, &header, &full_parent, ethereum, MAX_BLOBS_PER_BLOCK),
+            Err(BodyValidationError::BaseFee(GotExpected {
+                got: 1_000_000_000,
+                expected: 1_125_000_000
+            }))
+        );
+
+        // Chain with a lower blob limit and Optimism base fee parameters: the parent used three
+        // times its gas target, raising the base fee by 4%
+        let optimism = BaseFeeParams::optimism();
+        let two_blobs_body = BlockBody {
+            transactions: vec![test_signed_tx(Transaction::Eip4844(TxEip4844 {
+                blob_versioned_hashes: vec![B256::ZERO; 2],
+                ..Default::default()
+            }))],
+            ..body.clone()
+        };
+        assert_eq!(
+            validate_body_in_context(&two_blobs_body, &header, &parent, optimism, 1),
+            Err(BodyValidationError::BlobCount(GotExpected { got: 2, expected: 1 }))
+        );
+        assert_eq!(
+            validate_body_in_context(&body, &header, &parent, optimism, 1),
+            Err(BodyValidationError::BaseFee(GotExpected {
+                got: 1_000_000_000,
+                expected: 1_040_000_000
+            }))
+        );
+        let optimism_header = Header { base_fee_per_gas: Some(1_040_000_000), ..header.clone() };
+        assert_eq!(validate_body_in_context(&body, &optimism_header, &parent, optimism, 1), Ok(()));
+    }
+
+    #[test]
+    fn seal_block() {
+        use crate::{proofs::calculate_transaction_root, Transaction};
+        use alloy_consensus::{TxLegacy, EMPTY_OMMER_ROOT_HASH};
+        use alloy_primitives::Sealable;
+
+        let body = BlockBody {
+            transactions: vec![test_signed_tx(Transaction::Legacy(TxLegacy::default()))],
+            ..Default::default()
+        };
+        let header = Header {
+            number: 1,
+            transactions_root: calculate_transaction_root(&body.transactions),
+            ommers_hash: EMPTY_OMMER_ROOT_HASH,
+            ..Default::default()
+        };
+
+        let block = super::seal_block(header.clone(), body.clone()).unwrap();
+        assert_eq!(block.hash(), header.hash_slow());
+        assert_eq!(block.body, body);
+
+        // Roots of an empty block don't match the body
+        let empty_header = Header { ommers_hash: EMPTY_OMMER_ROOT_HASH, ..Default::default() };
+        assert_eq!(
+            super::seal_block(empty_header.clone(), body),
+            Err(BodyValidationError::TransactionsRoot(GotExpected {
+                got: header.transactions_root,
+                expected: empty_header.transactions_root
+            }))
+        );
+    }
+
+    #[test]
+    fn transactions_with_senders_lazy() {
+        use crate::{sign_message, Transaction};
+        use alloy_consensus::TxLegacy;
+        use alloy_primitives::{PrimitiveSignature as Signature, U256};
+        use reth_primitives_traits::{BlockBody as _, RecoveryError};
+
+        let mut body = BlockBody::default();
+        for nonce in 0..4 {
+            let tx = Transaction::Legacy(TxLegacy { nonce, ..Default::default() });
+            let signature =
+                sign_message(B256::with_last_byte(nonce as u8 + 1), tx.signature_hash()).unwrap();
+            body.transactions
+                .push(TransactionSigned::from_transaction_and_signature(tx, signature));
+        }
+
+        let (senders, transactions): (Vec<_>, Vec<_>) =
+            body.transactions_with_senders_lazy().unzip();
+        assert_eq!(senders.into_iter().collect::<Result<Vec<_>, _>>().ok(), body.recover_signers());
+        assert!(transactions.into_iter().eq(&body.transactions));
+
+        // Invalid signatures don't stop the iteration
+        body.transactions.insert(
+            0,
+            TransactionSigned::from_transaction_and_signature(
+                Transaction::Legacy(TxLegacy::default()),
+                Signature::new(U256::ZERO, U256::ZERO, false),
+            ),
+        );
+        let mut iter = body.transactions_with_senders_lazy();
+        assert_eq!(
+            iter.next().map(|(sender, _)| sender),
+            Some(Err(RecoveryError { tx_hash: body.transactions[0].hash() }))
+        );
+        assert_eq!(iter.count(), 4);
+    }
+
+    #[test]
+    fn validate_sender_nonce_ordering() {
+        use crate::{sign_message, Transaction};
+        use alloy_consensus::TxLegacy;
+        use reth_primitives_traits::BlockBody as _;
+
+        let signed = |key: u8, nonce: u64| {
+            let tx = Transaction::Legacy(TxLegacy { nonce, ..Default::default() });
+            let signature = sign_message(B256::with_last_byte(key), tx.signature_hash()).unwrap();
+            TransactionSigned::from_transaction_and_signature(tx, signature)
+        };
+
+        // Interleaved senders with consecutive nonces
+        let mut body = BlockBody {
+            transactions: vec![signed(1, 5), signed(2, 0), signed(1, 6), signed(2, 1)],
+            ..Default::default()
+        };
+        assert_eq!(body.validate_sender_nonce_ordering(), Ok(()));
+
+        // Nonce gap
+        body.transactions.push(signed(1, 8));
+        let sender = body.transactions[0].recover_signer().unwrap();
+        assert_eq!(
+            body.validate_sender_nonce_ordering(),
+            Err(BodyValidationError::SenderNonce {
+                index: 4,
+                sender,
+                nonce: GotExpected { got: 8, expected: 7 }
+            })
+        );
+
+        // Repeated nonce
+        body.transactions[4] = signed(2, 1);
+        assert_eq!(
+            body.validate_sender_nonce_ordering(),
+            Err(BodyValidationError::SenderNonce {
+                index: 4,
+                sender: body.transactions[1].recover_signer().unwrap(),
+                nonce: GotExpected { got: 1, expected: 2 }
+            })
+        );
+    }
+
+    #[test]
+    fn recover_signers_unchecked() {
+        use crate::{sign_message, Transaction};
+        use alloy_consensus::TxLegacy;
+        use alloy_primitives::{PrimitiveSignature as Signature, U256};
+        use reth_primitives_traits::BlockBody as _;
+
+        let tx = Transaction::Legacy(TxLegacy::default());
+        let signature = sign_message(B256::with_last_byte(1), tx.signature_hash()).unwrap();
+        let mut body = BlockBody {
+            transactions: vec![TransactionSigned::from_transaction_and_signature(
+                tx.clone(),
+                signature,
+            )],
+            ..Default::default()
+        };
+        assert_eq!(body.recover_signers_unchecked(), body.recover_signers());
+
+        // High `s` values are only rejected by the checked recovery
+        body.transactions.push(TransactionSigned::from_transaction_and_signature(
+            tx.clone(),
+            Signature::new(signature.r(), U256::MAX >> 1, signature.v()),
+        ));
+        assert_eq!(body.recover_signers(), None);
+        assert_eq!(body.recover_signers_unchecked().map(|signers| signers.len()), Some(2));
+
+        // Unrecoverable signatures fail the whole body
+        body.transactions.push(TransactionSigned::from_transaction_and_signature(
+            tx,
+            Signature::new(U256::ZERO, U256::ZERO, false),
+        ));
+        assert_eq!(body.recover_signers_unchecked(), None);
+    }
+
+    #[test]
+    fn recover_signers_into() {
+        use crate::{sign_message, transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD, Transaction};
+        use alloy_consensus::TxLegacy;
+        use alloy_primitives::{PrimitiveSignature as Signature, U256};
+        use reth_primitives_traits::BlockBody as _;
+
+        let body = |count: usize| BlockBody {
+            transactions: (0..count)
+                .map(|nonce| {
+                    let tx =
+                        Transaction::Legacy(TxLegacy { nonce: nonce as u64, ..Default::default() });
+                    let signature = sign_message(
+                        B256::with_last_byte(nonce as u8 % 8 + 1),
+                        tx.signature_hash(),
+                    )
+                    .unwrap();
+                    TransactionSigned::from_transaction_and_signature(tx, signature)
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        // The result doesn't depend on the threshold
+        let sequential = body(16);
+        assert_eq!(
+            sequential.recover_signers_with_threshold(usize::MAX),
+            sequential.recover_signers_with_threshold(0)
+        );
+
+        // Recovered in parallel on multi-threaded machines
+        let large = body(PARALLEL_SENDER_RECOVERY_THRESHOLD.saturating_mul(2).min(64));
+        let mut signers = Vec::new();
+        assert!(large.recover_signers_into(&mut signers));
+        assert_eq!(Some(signers.clone()), large.recover_signers());
+        let capacity = signers.capacity();
+
+        // The buffer is cleared and its allocation is reused
+        let small = body(2);
+        assert!(small.recover_signers_into(&mut signers));
+        assert_eq!(Some(signers.clone()), small.recover_signers());
+        assert_eq!(signers.capacity(), capacity);
+
+        let mut invalid = body(3);
+        invalid.transactions.push(TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy::default()),
+            Signature::new(U256::ZERO, U256::ZERO, false),
+        ));
+        assert!(!invalid.recover_signers_into(&mut signers));
+        assert!(signers.is_empty());
+    }
 }
@@ -32,15 +32,17 @@ pub use reth_static_file_types as static_file;
 pub mod transaction;
 #[cfg(any(test, feature = "arbitrary"))]
 pub use block::{generate_valid_header, valid_header_strategy};
-pub use block::{Block, BlockBody, BlockWithSenders, SealedBlock, SealedBlockWithSenders};
+pub use block::{
+    seal_block, Block, BlockBody, BlockWithSenders, SealedBlock, SealedBlockWithSenders,
+};
 #[cfg(feature = "reth-codec")]
 pub use compression::*;
 pub use receipt::{
     gas_spent_by_transactions, Receipt, ReceiptWithBloom, ReceiptWithBloomRef, Receipts,
 };
 pub use reth_primitives_traits::{
-    logs_bloom, Account, Bytecode, GotExpected, GotExpectedBoxed, Header, HeaderError, Log,
-    LogData, NodePrimitives, SealedHeader, StorageEntry,
+    logs_bloom, Account, BodyValidationError, Bytecode, GotExpected, GotExpectedBoxed, Header,
+    HeaderError, Log, LogData, NodePrimitives, SealedHeader, StorageEntry,
 };
 pub use static_file::StaticFileSegment;
 
@@ -90,6 +92,7 @@ impl reth_primitives_traits::FullNodePrimitives for EthPrimitives {
 #[cfg(not(feature = "reth-codec"))]
 impl NodePrimitives for EthPrimitives {
     type Block = crate::Block;
+    type BlockHeader = Header;
     type SignedTx = crate::TransactionSigned;
     type TxType = crate::TxType;
     type Receipt = crate::Receipt;
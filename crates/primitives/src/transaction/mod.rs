@@ -19,16 +19,25 @@ use alloy_rlp::{Decodable, Encodable, Error as RlpError, Header};
 use core::mem;
 use derive_more::{AsRef, Deref};
 use once_cell as _;
-#[cfg(not(feature = "std"))]
-use once_cell::sync::Lazy as LazyLock;
 #[cfg(feature = "optimism")]
 use op_alloy_consensus::DepositTransaction;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use reth_primitives_traits::InMemorySize;
 use serde::{Deserialize, Serialize};
 use signature::decode_with_eip155_chain_id;
+
 #[cfg(feature = "std")]
-use std::sync::LazyLock;
+pub use reth_primitives_traits::transaction::PARALLEL_SENDER_RECOVERY_THRESHOLD;
+
+/// Expected number of transactions where we can expect a speed-up by recovering the senders in
+/// parallel.
+#[cfg(not(feature = "std"))]
+pub static PARALLEL_SENDER_RECOVERY_THRESHOLD: once_cell::sync::Lazy<usize> =
+    once_cell::sync::Lazy::new(|| match rayon::current_num_threads() {
+        0..=1 => usize::MAX,
+        2..=8 => 10,
+        _ => 5,
+    });
 
 pub use error::{
     InvalidTransactionError, TransactionConversionError, TryFromRecoveredTransactionError,
@@ -74,15 +83,6 @@ use revm_primitives::{AuthorizationList, TxEnv};
 /// Either a transaction hash or number.
 pub type TxHashOrNumber = BlockHashOrNumber;
 
-/// Expected number of transactions where we can expect a speed-up by recovering the senders in
-/// parallel.
-pub static PARALLEL_SENDER_RECOVERY_THRESHOLD: LazyLock<usize> =
-    LazyLock::new(|| match rayon::current_num_threads() {
-        0..=1 => usize::MAX,
-        2..=8 => 10,
-        _ => 5,
-    });
-
 /// A raw transaction.
 ///
 /// Transaction types were introduced in [EIP-2718](https://eips.ethereum.org/EIPS/eip-2718).
@@ -1362,6 +1362,13 @@ impl SignedTransaction for TransactionSigned {
     }
 }
 
+impl reth_primitives_traits::MaybeBlobSidecar for TransactionSigned {
+    /// Always `false`, as [`Transaction::Eip4844`] can't carry the blob sidecar.
+    fn has_blob_sidecar(&self) -> bool {
+        false
+    }
+}
+
 impl reth_primitives_traits::FillTxEnv for TransactionSigned {
     fn fill_tx_env(&self, tx_env: &mut TxEnv, sender: Address) {
         tx_env.caller = sender;
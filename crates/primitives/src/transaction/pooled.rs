@@ -595,6 +595,12 @@ impl PooledTransactionsElementEcRecovered {
     }
 }
 
+impl reth_primitives_traits::MaybeBlobSidecar for PooledTransactionsElement {
+    fn has_blob_sidecar(&self) -> bool {
+        matches!(self, Self::BlobTransaction(_))
+    }
+}
+
 /// Converts a `TransactionSignedEcRecovered` into a `PooledTransactionsElementEcRecovered`.
 impl TryFrom<TransactionSignedEcRecovered> for PooledTransactionsElementEcRecovered {
     type Error = TransactionConversionError;
@@ -699,4 +705,44 @@ mod tests {
         let res = PooledTransactionsElement::decode_2718(&mut &data[..]);
         assert_matches!(res, Ok(_tx));
     }
+
+    #[test]
+    fn reject_inline_blobs() {
+        use crate::BlockBody;
+        use reth_primitives_traits::{
+            validate_transactions_no_inline_blobs, BlockBody as _, BodyValidationError,
+        };
+
+        let legacy = PooledTransactionsElement::Legacy {
+            transaction: TxLegacy::default(),
+            signature: Signature::test_signature(),
+            hash: B256::ZERO,
+        };
+        let blob = PooledTransactionsElement::BlobTransaction(BlobTransaction {
+            hash: B256::ZERO,
+            signature: Signature::test_signature(),
+            transaction: TxEip4844WithSidecar {
+                tx: TxEip4844::default(),
+                sidecar: BlobTransactionSidecar::default(),
+            },
+        });
+        assert_eq!(validate_transactions_no_inline_blobs(&[legacy.clone()]), Ok(()));
+        assert_eq!(
+            validate_transactions_no_inline_blobs(&[legacy, blob]),
+            Err(BodyValidationError::InlineBlobs { index: 1 })
+        );
+
+        // Blob transactions in the consensus form reference the blobs only by their hashes
+        let body = BlockBody {
+            transactions: vec![TransactionSigned::from_transaction_and_signature(
+                Transaction::Eip4844(TxEip4844 {
+                    blob_versioned_hashes: vec![B256::ZERO],
+                    ..Default::default()
+                }),
+                Signature::test_signature(),
+            )],
+            ..Default::default()
+        };
+        assert_eq!(body.validate_no_inline_blobs(), Ok(()));
+    }
 }
@@ -5,7 +5,7 @@ use reth_db::transaction::DbTxMut;
 use reth_exex_types::FinishedExExHeight;
 use reth_provider::{
     providers::StaticFileProvider, BlockReader, DBProvider, DatabaseProviderFactory,
-    PruneCheckpointWriter, StaticFileProviderFactory, TransactionsProvider,
+    PruneCheckpointReader, PruneCheckpointWriter, StaticFileProviderFactory, TransactionsProvider,
 };
 use reth_prune_types::PruneModes;
 use std::time::Duration;
@@ -77,7 +77,10 @@ impl PrunerBuilder {
     pub fn build_with_provider_factory<PF>(self, provider_factory: PF) -> Pruner<PF::ProviderRW, PF>
     where
         PF: DatabaseProviderFactory<
-                ProviderRW: PruneCheckpointWriter + BlockReader + StaticFileProviderFactory,
+                ProviderRW: PruneCheckpointWriter
+                                + PruneCheckpointReader
+                                + BlockReader
+                                + StaticFileProviderFactory,
             > + StaticFileProviderFactory<
                 Primitives = <PF::ProviderRW as StaticFileProviderFactory>::Primitives,
             >,
@@ -104,6 +107,7 @@ impl PrunerBuilder {
         Provider: StaticFileProviderFactory
             + DBProvider<Tx: DbTxMut>
             + BlockReader
+            + PruneCheckpointReader
             + PruneCheckpointWriter
             + TransactionsProvider,
     {
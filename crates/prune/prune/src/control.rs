@@ -0,0 +1,31 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Handle for pausing and resuming a [`Pruner`](crate::Pruner) at runtime, obtained via
+/// [`Pruner::control_handle`](crate::Pruner::control_handle).
+///
+/// Clones of the handle share the state, so pruning can be toggled from another thread, e.g. an
+/// admin RPC, without reconstructing the pruner.
+#[derive(Debug, Clone, Default)]
+pub struct PrunerControl {
+    paused: Arc<AtomicBool>,
+}
+
+impl PrunerControl {
+    /// Pauses pruning. Runs started after this call return without pruning anything.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resumes pruning.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if pruning is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+}
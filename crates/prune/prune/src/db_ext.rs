@@ -1,22 +1,27 @@
-use std::{fmt::Debug, ops::RangeBounds};
+use std::{
+    fmt::Debug,
+    ops::{RangeBounds, RangeInclusive},
+};
 
 use reth_db::{
     cursor::{DbCursorRO, DbCursorRW, RangeWalker},
     table::{Table, TableRow},
     transaction::DbTxMut,
-    DatabaseError,
+    DatabaseError, RawKey, RawTable,
 };
 use reth_prune_types::PruneLimiter;
 use tracing::debug;
 
 pub(crate) trait DbTxPruneExt: DbTxMut {
-    /// Prune the table for the specified pre-sorted key iterator.
+    /// Prune the table for the specified pre-sorted key iterator. If `dry_run` is set, the rows
+    /// are only counted as pruned without deleting them.
     ///
     /// Returns number of rows pruned.
     fn prune_table_with_iterator<T: Table>(
         &self,
         keys: impl IntoIterator<Item = T::Key>,
         limiter: &mut PruneLimiter,
+        dry_run: bool,
         mut delete_callback: impl FnMut(TableRow<T>),
     ) -> Result<(usize, bool), DatabaseError> {
         let mut cursor = self.cursor_write::<T>()?;
@@ -39,7 +44,9 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
 
             let row = cursor.seek_exact(key)?;
             if let Some(row) = row {
-                cursor.delete_current()?;
+                if !dry_run {
+                    cursor.delete_current()?;
+                }
                 limiter.increment_deleted_entries_count();
                 deleted_entries += 1;
                 delete_callback(row);
@@ -50,13 +57,15 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
         Ok((deleted_entries, done))
     }
 
-    /// Prune the table for the specified key range.
+    /// Prune the table for the specified key range. If `dry_run` is set, the rows are only
+    /// counted as pruned without deleting them.
     ///
     /// Returns number of rows pruned.
     fn prune_table_with_range<T: Table>(
         &self,
         keys: impl RangeBounds<T::Key> + Clone + Debug,
         limiter: &mut PruneLimiter,
+        dry_run: bool,
         mut skip_filter: impl FnMut(&TableRow<T>) -> bool,
         mut delete_callback: impl FnMut(TableRow<T>),
     ) -> Result<(usize, bool), DatabaseError> {
@@ -83,6 +92,7 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
             let done = self.prune_table_with_range_step(
                 &mut walker,
                 limiter,
+                dry_run,
                 &mut skip_filter,
                 &mut delete_callback,
             )?;
@@ -96,7 +106,54 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
         Ok((deleted_entries, done))
     }
 
-    /// Steps once with the given walker and prunes the entry in the table.
+    /// Prune the table for the specified contiguous key range, walking the raw table so that the
+    /// rows aren't decoded. If `dry_run` is set, the rows are only counted as pruned without
+    /// deleting them.
+    ///
+    /// Returns number of rows pruned, whether the whole range was pruned, and the key of the last
+    /// pruned row.
+    fn prune_table_with_raw_range<T: Table>(
+        &self,
+        keys: RangeInclusive<T::Key>,
+        limiter: &mut PruneLimiter,
+        dry_run: bool,
+    ) -> Result<(usize, bool, Option<T::Key>), DatabaseError> {
+        let (start, end) = keys.into_inner();
+        let mut cursor = self.cursor_write::<RawTable<T>>()?;
+        let mut walker = cursor.walk_range(RawKey::new(start)..=RawKey::new(end))?;
+
+        let mut deleted_entries = 0;
+        let mut last_pruned_key = None;
+
+        let done = loop {
+            if limiter.is_limit_reached() {
+                debug!(
+                    target: "providers::db",
+                    ?limiter,
+                    deleted_entries_limit = %limiter.is_deleted_entries_limit_reached(),
+                    time_limit = %limiter.is_time_limit_reached(),
+                    table = %T::NAME,
+                    "Pruning limit reached"
+                );
+                break false
+            }
+
+            let Some(res) = walker.next() else { break true };
+            let (key, _) = res?;
+
+            if !dry_run {
+                walker.delete_current()?;
+            }
+            limiter.increment_deleted_entries_count();
+            deleted_entries += 1;
+            last_pruned_key = Some(key);
+        };
+
+        Ok((deleted_entries, done, last_pruned_key.map(|key| key.key()).transpose()?))
+    }
+
+    /// Steps once with the given walker and prunes the entry in the table. If `dry_run` is set,
+    /// the entry is only counted as pruned without deleting it.
     ///
     /// Returns `true` if the walker is finished, `false` if it may have more data to prune.
     ///
@@ -107,6 +164,7 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
         &self,
         walker: &mut RangeWalker<'_, T, Self::CursorMut<T>>,
         limiter: &mut PruneLimiter,
+        dry_run: bool,
         skip_filter: &mut impl FnMut(&TableRow<T>) -> bool,
         delete_callback: &mut impl FnMut(TableRow<T>),
     ) -> Result<bool, DatabaseError> {
@@ -115,7 +173,9 @@ pub(crate) trait DbTxPruneExt: DbTxMut {
         let row = res?;
 
         if !skip_filter(&row) {
-            walker.delete_current()?;
+            if !dry_run {
+                walker.delete_current()?;
+            }
             limiter.increment_deleted_entries_count();
             delete_callback(row);
         }
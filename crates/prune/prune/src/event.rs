@@ -1,12 +1,81 @@
 use alloy_primitives::BlockNumber;
-use reth_prune_types::PrunedSegmentInfo;
-use std::time::Duration;
+use reth_prune_types::{PruneSegment, PrunedSegmentInfo};
+use reth_tokio_util::EventSender;
+use std::{fmt::Debug, time::Duration};
+use tokio::sync::broadcast;
 
 /// An event emitted by a [Pruner][crate::Pruner].
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum PrunerEvent {
     /// Emitted when pruner started running.
     Started { tip_block_number: BlockNumber },
+    /// Emitted before pruning a segment.
+    SegmentStarted {
+        segment: PruneSegment,
+        /// Target block up to which the segment is pruned, inclusive.
+        to_block: BlockNumber,
+        /// Highest block pruned by the previous runs, if any.
+        previous_checkpoint: Option<BlockNumber>,
+    },
+    /// Emitted after pruning a segment.
+    SegmentFinished {
+        segment: PruneSegment,
+        /// Number of entries pruned in this run.
+        pruned: usize,
+        /// Whether the segment is fully pruned up to the target block.
+        done: bool,
+        /// Rough share of the blocks up to the target block that are pruned, in percent.
+        percent_complete: u8,
+    },
     /// Emitted when pruner finished running.
-    Finished { tip_block_number: BlockNumber, elapsed: Duration, stats: Vec<PrunedSegmentInfo> },
+    Finished {
+        tip_block_number: BlockNumber,
+        elapsed: Duration,
+        stats: Vec<PrunedSegmentInfo>,
+        budget_consumers: Vec<(PruneSegment, usize)>,
+        effective_delete_limit: usize,
+        delete_limit_remaining: usize,
+    },
+}
+
+/// A sink receiving every [`PrunerEvent`] emitted by a [Pruner][crate::Pruner], in addition to the
+/// listeners of [`Pruner::events`][crate::Pruner::events].
+///
+/// Allows routing the events into an existing event bus without adapting the event stream.
+pub trait PrunerEventSink: Debug + Send + Sync {
+    /// Emits the event.
+    fn emit(&self, event: PrunerEvent);
+}
+
+/// Notifies the listeners and the sink of a [Pruner][crate::Pruner] about the [`PrunerEvent`]s.
+#[derive(Debug, Default)]
+pub(crate) struct PrunerEventNotifier {
+    pub(crate) sender: EventSender<PrunerEvent>,
+    /// Optional sink receiving the events in addition to the listeners.
+    pub(crate) sink: Option<Box<dyn PrunerEventSink>>,
+    /// The most recently emitted event, see
+    /// [`Pruner::take_last_event`][crate::Pruner::take_last_event].
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) last_event: Option<PrunerEvent>,
+}
+
+impl PrunerEventNotifier {
+    /// Notifies the listeners and the sink about the event.
+    pub(crate) fn notify(&mut self, event: PrunerEvent) {
+        if let Some(sink) = &self.sink {
+            sink.emit(event.clone());
+        }
+        #[cfg(any(test, feature = "test-utils"))]
+        {
+            self.last_event = Some(event.clone());
+        }
+        self.sender.notify(event);
+    }
+}
+
+impl PrunerEventSink for broadcast::Sender<PrunerEvent> {
+    fn emit(&self, event: PrunerEvent) {
+        // Sending fails only if there are no subscribers, in which case the event is dropped
+        let _ = self.send(event);
+    }
 }
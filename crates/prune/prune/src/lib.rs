@@ -10,18 +10,21 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 mod builder;
+mod control;
 mod db_ext;
 mod error;
 mod event;
 mod metrics;
 mod pruner;
+mod rate_limit;
 pub mod segments;
 
 use crate::metrics::Metrics;
 pub use builder::PrunerBuilder;
+pub use control::PrunerControl;
 pub use error::PrunerError;
-pub use event::PrunerEvent;
-pub use pruner::{Pruner, PrunerResult, PrunerWithFactory, PrunerWithResult};
+pub use event::{PrunerEvent, PrunerEventSink};
+pub use pruner::{Pruner, PrunerResult, PrunerWithFactory, PrunerWithResult, StaticPruner};
 
 // Re-export prune types
 #[doc(inline)]
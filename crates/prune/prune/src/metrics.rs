@@ -1,5 +1,5 @@
 use reth_metrics::{
-    metrics::{Gauge, Histogram},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
 use reth_prune_types::PruneSegment;
@@ -10,6 +10,8 @@ use std::collections::HashMap;
 pub(crate) struct Metrics {
     /// Pruning duration
     pub(crate) duration_seconds: Histogram,
+    /// Time spent waiting for the read-write database provider
+    pub(crate) provider_acquire_seconds: Histogram,
     #[metric(skip)]
     prune_segments: HashMap<PruneSegment, PrunerSegmentMetrics>,
 }
@@ -34,4 +36,11 @@ pub(crate) struct PrunerSegmentMetrics {
     pub(crate) duration_seconds: Histogram,
     /// Highest pruned block per segment
     pub(crate) highest_pruned_block: Gauge,
+    /// Total number of entries pruned, i.e. deleted from the database, per segment
+    pub(crate) pruned_rows: Counter,
+    /// Total number of blocks pruned per segment
+    pub(crate) pruned_blocks: Counter,
+    /// Number of runs in which the segment finished pruning entries without advancing its
+    /// checkpoint
+    pub(crate) checkpoint_stall: Counter,
 }
@@ -1,19 +1,30 @@
 //! Support for pruning.
 
 use crate::{
-    segments::{PruneInput, Segment},
-    Metrics, PrunerError, PrunerEvent,
+    event::PrunerEventNotifier,
+    rate_limit::DeletionRateLimiter,
+    segments::{DatabaseSizeTarget, PruneInput, Segment, SegmentVisitor, Segments, TableSizes},
+    Metrics, PrunerControl, PrunerError, PrunerEvent, PrunerEventSink,
 };
 use alloy_primitives::BlockNumber;
 use reth_exex_types::FinishedExExHeight;
 use reth_provider::{
     DBProvider, DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
 };
-use reth_prune_types::{PruneLimiter, PruneProgress, PrunedSegmentInfo, PrunerOutput};
-use reth_tokio_util::{EventSender, EventStream};
-use std::time::{Duration, Instant};
+use reth_prune_types::{
+    PruneInterruptReason, PruneLimiter, PruneProgress, PruneSegment, PrunedSegmentInfo,
+    PrunerOutput,
+};
+use reth_tokio_util::EventStream;
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
 use tokio::sync::watch;
-use tracing::debug;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, trace};
 
 /// Result of [`Pruner::run`] execution.
 pub type PrunerResult = Result<PrunerOutput, PrunerError>;
@@ -24,53 +35,135 @@ pub type PrunerWithResult<S, DB> = (Pruner<S, DB>, PrunerResult);
 /// Pruner with preset provider factory.
 pub type PrunerWithFactory<PF> = Pruner<<PF as DatabaseProviderFactory>::ProviderRW, PF>;
 
+/// Pruner with preset provider factory and a fixed set of segments `S`, e.g. a tuple of concrete
+/// segment types, that are run with static dispatch.
+pub type StaticPruner<PF, S> = Pruner<<PF as DatabaseProviderFactory>::ProviderRW, PF, S>;
+
 /// Pruning routine. Main pruning logic happens in [`Pruner::run`].
+///
+/// By default, segments are stored as trait objects. Embedders with a fixed set of segments can
+/// use any other [`Segments`] implementation instead, see [`StaticPruner`].
 #[derive(Debug)]
-pub struct Pruner<Provider, PF> {
+pub struct Pruner<Provider, PF, S = Vec<Box<dyn Segment<Provider>>>> {
     /// Provider factory. If pruner is initialized without it, it will be set to `()`.
     provider_factory: PF,
-    segments: Vec<Box<dyn Segment<Provider>>>,
+    segments: S,
     /// Minimum pruning interval measured in blocks. All prune segments are checked and, if needed,
     /// pruned, when the chain advances by the specified number of blocks.
     min_block_interval: usize,
     /// Previous tip block number when the pruner was run. Even if no data was pruned, this block
     /// number is updated with the tip block number the pruner was called with. It's used in
     /// conjunction with `min_block_interval` to determine when the pruning needs to be initiated.
+    ///
+    /// It's intentionally not persisted: the delete limit of a run doesn't depend on the number
+    /// of blocks since the previous run, so restoring it after a restart would only delay the
+    /// first run by up to `min_block_interval` blocks.
     previous_tip_block_number: Option<BlockNumber>,
+    /// Minimum pruning interval measured in time, see [`Pruner::with_min_time_interval`].
+    min_time_interval: Option<Duration>,
+    /// Time of the previous run, used in conjunction with `min_time_interval`.
+    previous_run_instant: Option<Instant>,
+    /// Number of blocks to skip pruning for after a run drained the data left by the previous run,
+    /// see [`Pruner::with_cooldown`].
+    cooldown: u64,
+    /// Whether the previous run returned [`PruneProgress::HasMoreData`].
+    has_more_data: bool,
+    /// Tip block number from which the pruning is needed again during the cooldown.
+    cooldown_until: Option<BlockNumber>,
     /// Maximum total entries to prune (delete from database) per run.
     delete_limit: usize,
+    /// Maximum entries to prune per run for the segments with their own delete limit, see
+    /// [`Pruner::with_segment_delete_limits`].
+    segment_delete_limits: BTreeMap<PruneSegment, usize>,
     /// Maximum time for a one pruner run.
     timeout: Option<Duration>,
+    /// Optional maximum size of the database, see [`Pruner::with_size_target`].
+    size_target: Option<DatabaseSizeTarget>,
+    /// Optional limit on the sustained number of deleted entries per second, see
+    /// [`Pruner::with_rate_limit`].
+    rate_limiter: Option<DeletionRateLimiter>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Cumulative number of entries pruned per segment across all runs.
+    lifetime_pruned: BTreeMap<PruneSegment, u64>,
+    /// Number of runs per segment in which the segment finished pruning entries without advancing
+    /// its checkpoint, see [`Pruner::checkpoint_stalls`].
+    checkpoint_stalls: BTreeMap<PruneSegment, u64>,
+    /// Lowest block still needed by each registered downstream consumer, keyed by consumer name.
+    consumer_floors: BTreeMap<String, BlockNumber>,
+    /// Whether the segment that is pruned first rotates between runs, see
+    /// [`Pruner::with_round_robin_ordering`].
+    round_robin_ordering: bool,
+    /// Index of the segment that is pruned first in the next run.
+    first_segment: usize,
+    /// Handle for pausing and resuming the pruning, see [`Pruner::control_handle`].
+    control: PrunerControl,
     #[doc(hidden)]
     metrics: Metrics,
-    event_sender: EventSender<PrunerEvent>,
+    notifier: PrunerEventNotifier,
+    /// The segments are generic over the provider, but don't necessarily store it in their type.
+    _provider: PhantomData<fn() -> Provider>,
 }
 
-impl<Provider> Pruner<Provider, ()> {
-    /// Creates a new [Pruner] without a provider factory.
-    pub fn new(
-        segments: Vec<Box<dyn Segment<Provider>>>,
+impl<Provider, PF, S> Pruner<Provider, PF, S> {
+    /// Creates a new pruner with the given components, and the defaults of the other settings.
+    fn from_parts(
+        provider_factory: PF,
+        segments: S,
         min_block_interval: usize,
         delete_limit: usize,
         timeout: Option<Duration>,
         finished_exex_height: watch::Receiver<FinishedExExHeight>,
     ) -> Self {
         Self {
-            provider_factory: (),
+            provider_factory,
             segments,
             min_block_interval,
             previous_tip_block_number: None,
+            min_time_interval: None,
+            previous_run_instant: None,
+            cooldown: 0,
+            has_more_data: false,
+            cooldown_until: None,
             delete_limit,
+            segment_delete_limits: BTreeMap::new(),
             timeout,
+            size_target: None,
+            rate_limiter: None,
             finished_exex_height,
+            lifetime_pruned: BTreeMap::new(),
+            checkpoint_stalls: BTreeMap::new(),
+            consumer_floors: BTreeMap::new(),
+            round_robin_ordering: false,
+            first_segment: 0,
+            control: PrunerControl::default(),
             metrics: Metrics::default(),
-            event_sender: Default::default(),
+            notifier: PrunerEventNotifier::default(),
+            _provider: PhantomData,
         }
     }
 }
 
+impl<Provider> Pruner<Provider, ()> {
+    /// Creates a new [Pruner] without a provider factory.
+    pub fn new(
+        segments: Vec<Box<dyn Segment<Provider>>>,
+        min_block_interval: usize,
+        delete_limit: usize,
+        timeout: Option<Duration>,
+        finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    ) -> Self {
+        Self::from_parts(
+            (),
+            segments,
+            min_block_interval,
+            delete_limit,
+            timeout,
+            finished_exex_height,
+        )
+    }
+}
+
 impl<PF> Pruner<PF::ProviderRW, PF>
 where
     PF: DatabaseProviderFactory,
@@ -84,27 +177,227 @@ where
         timeout: Option<Duration>,
         finished_exex_height: watch::Receiver<FinishedExExHeight>,
     ) -> Self {
-        Self {
+        Self::from_parts(
             provider_factory,
             segments,
             min_block_interval,
-            previous_tip_block_number: None,
             delete_limit,
             timeout,
             finished_exex_height,
-            metrics: Metrics::default(),
-            event_sender: Default::default(),
-        }
+        )
+    }
+}
+
+impl<PF, S> StaticPruner<PF, S>
+where
+    PF: DatabaseProviderFactory,
+    S: Segments<PF::ProviderRW>,
+{
+    /// Creates a new pruner with the given provider factory and a fixed set of segments.
+    pub fn new_static_with_factory(
+        provider_factory: PF,
+        segments: S,
+        min_block_interval: usize,
+        delete_limit: usize,
+        timeout: Option<Duration>,
+        finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    ) -> Self {
+        Self::from_parts(
+            provider_factory,
+            segments,
+            min_block_interval,
+            delete_limit,
+            timeout,
+            finished_exex_height,
+        )
     }
 }
 
-impl<Provider, S> Pruner<Provider, S>
+impl<Provider, PF, S> Pruner<Provider, PF, S>
 where
     Provider: PruneCheckpointReader + PruneCheckpointWriter,
+    S: Segments<Provider>,
 {
+    /// Limits the sustained number of deleted entries (rows in the database) to
+    /// `deletions_per_second`, smoothing the pruning I/O instead of deleting up to `delete_limit`
+    /// entries at once.
+    ///
+    /// Before pruning a segment, the pruner waits until a batch of up to `deletions_per_second`
+    /// entries is allowed, and the segment prunes at most that batch in the run. The
+    /// `delete_limit` still caps the total number of deleted entries per run, and the waiting
+    /// counts towards the run timeout: if the timeout would be reached while waiting, the run
+    /// stops and the remaining data is pruned by the next runs.
+    pub fn with_rate_limit(mut self, deletions_per_second: NonZeroUsize) -> Self {
+        self.rate_limiter = Some(DeletionRateLimiter::new(deletions_per_second));
+        self
+    }
+
+    /// Stops pruning once the total size of all database tables, as returned by `table_sizes`, is
+    /// within `target_size` bytes.
+    ///
+    /// The size is checked before pruning each segment. Sizes are usually queried in a separate
+    /// transaction, see [`TableSizes`], so the entries deleted in the current run only count
+    /// towards the target after they're committed, and the next run stops early instead. Combine
+    /// with [`SizeTargetedSegment`](crate::segments::SizeTargetedSegment) to calculate the prune
+    /// target of a segment from the size of its table.
+    pub fn with_size_target(mut self, target_size: u64, table_sizes: TableSizes) -> Self {
+        self.size_target = Some(DatabaseSizeTarget { target_size, table_sizes });
+        self
+    }
+
+    /// Requires at least `interval` to pass since the previous run before the pruning is needed
+    /// again, see [`Pruner::is_pruning_needed`].
+    ///
+    /// The time interval doesn't replace the `min_block_interval`: the pruning is needed only when
+    /// the chain advanced by `min_block_interval` blocks and `interval` passed since the previous
+    /// run. Set the `min_block_interval` to zero to prune purely based on time.
+    pub fn with_min_time_interval(mut self, interval: Duration) -> Self {
+        self.min_time_interval = Some(interval);
+        self
+    }
+
+    /// Skips pruning for `blocks` blocks after a run drained the data left by the previous run,
+    /// giving the storage engine a break after heavy pruning.
+    ///
+    /// The cooldown starts after a run that prunes all segments to completion, if the previous run
+    /// returned [`PruneProgress::HasMoreData`], e.g. because it reached the delete limit or the
+    /// timeout. [`Pruner::is_pruning_needed`] then returns `false` until the chain advances by
+    /// `blocks` from the tip of that run. The `min_block_interval` is counted from the same tip, so
+    /// a cooldown not longer than the `min_block_interval` has no effect. A run returning
+    /// [`PruneProgress::HasMoreData`], e.g. when [`Pruner::run`] is called directly with a new
+    /// backlog, resets the cooldown until a run prunes all segments to completion again.
+    pub fn with_cooldown(mut self, blocks: u64) -> Self {
+        self.cooldown = blocks;
+        self
+    }
+
+    /// Gives the segments in `limits` their own maximum number of entries to prune per run,
+    /// instead of sharing the `delete_limit` with other segments.
+    ///
+    /// Segments not listed in `limits` keep sharing the `delete_limit`. The listed segments prune
+    /// up to their own limit regardless of the entries pruned by the other segments, even if the
+    /// `delete_limit` is already exhausted, so they can't be starved of their budget. Entries
+    /// pruned by the listed segments still count towards the `delete_limit`, limiting the
+    /// segments sharing it that are pruned afterwards.
+    pub fn with_segment_delete_limits(mut self, limits: BTreeMap<PruneSegment, usize>) -> Self {
+        self.segment_delete_limits = limits;
+        self
+    }
+
+    /// Rotates the segment that is pruned first by one position on every run, if `enabled`.
+    ///
+    /// Segments share the `delete_limit` of a run, so with the fixed order the last segments are
+    /// starved while the first ones have a lot to prune. With the rotation, every segment
+    /// eventually gets the first pick at the delete limit.
+    pub fn with_round_robin_ordering(mut self, enabled: bool) -> Self {
+        self.round_robin_ordering = enabled;
+        self
+    }
+
+    /// Returns a handle for pausing and resuming the pruning. While paused, [`Pruner::run`] returns
+    /// [`PruneProgress::Finished`] without pruning anything.
+    pub fn control_handle(&self) -> PrunerControl {
+        self.control.clone()
+    }
+
     /// Listen for events on the pruner.
     pub fn events(&self) -> EventStream<PrunerEvent> {
-        self.event_sender.new_listener()
+        self.notifier.sender.new_listener()
+    }
+
+    /// Sets the sink that receives all events, in addition to the listeners of [`Self::events`].
+    ///
+    /// Replaces the previously set sink, if any.
+    pub fn set_event_sink(&mut self, sink: impl PrunerEventSink + 'static) {
+        self.notifier.sink = Some(Box::new(sink));
+    }
+
+    /// Takes the most recently emitted event, allowing to inspect it without listening to
+    /// [`Self::events`].
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn take_last_event(&mut self) -> Option<PrunerEvent> {
+        self.notifier.last_event.take()
+    }
+
+    /// Returns the cumulative number of entries pruned per segment across all runs of this
+    /// [Pruner].
+    ///
+    /// Compared against the growth of the underlying tables, it shows whether the retention of a
+    /// segment keeps up with the chain.
+    pub fn lifetime_pruned(&self) -> BTreeMap<PruneSegment, u64> {
+        self.lifetime_pruned.clone()
+    }
+
+    /// Suggests a `delete_limit` that drains the backlog of `backlog_rows` entries spanning
+    /// `backlog_blocks` blocks in `target_runs` runs, assuming that the backlog doesn't grow in the
+    /// meantime.
+    ///
+    /// If a run prunes at most `max_blocks_per_run` blocks, the backlog can't be drained in fewer
+    /// than `backlog_blocks / max_blocks_per_run` runs, so the suggestion is spread over that many
+    /// runs instead, as a run can't use a larger limit anyway.
+    ///
+    /// The suggestion is rounded up, so the backlog is drained in at most the resulting number of
+    /// runs. Zero `target_runs` or `max_blocks_per_run` is treated as one.
+    pub fn suggest_delete_limit(
+        &self,
+        backlog_rows: u64,
+        backlog_blocks: u64,
+        target_runs: usize,
+        max_blocks_per_run: Option<u64>,
+    ) -> usize {
+        let min_runs = max_blocks_per_run
+            .map_or(0, |max_blocks_per_run| backlog_blocks.div_ceil(max_blocks_per_run.max(1)));
+        let runs = (target_runs as u64).max(min_runs).max(1);
+        usize::try_from(backlog_rows.div_ceil(runs)).unwrap_or(usize::MAX)
+    }
+
+    /// Returns the number of runs per segment in which the segment reported that it finished
+    /// pruning and deleted entries, but its checkpoint block didn't advance.
+    ///
+    /// This indicates a buggy segment that deletes data without recording the progress, so the
+    /// same range is pruned over and over again.
+    pub fn checkpoint_stalls(&self) -> BTreeMap<PruneSegment, u64> {
+        self.checkpoint_stalls.clone()
+    }
+
+    /// Registers or updates the floor of a downstream consumer, e.g. a live indexer, identified by
+    /// `name`. The floor is the lowest block which data the consumer still needs.
+    ///
+    /// Segments pruning according to the user configuration never prune the floor block or any
+    /// block above the lowest registered floor. Consumers should update their floor as they
+    /// advance, otherwise pruning stalls. Segments pruning data already moved to static files are
+    /// not affected.
+    pub fn register_consumer_floor(&mut self, name: &str, block: BlockNumber) {
+        self.consumer_floors.insert(name.to_string(), block);
+    }
+
+    /// Projects the checkpoint of each segment over `runs` future runs, assuming that the chain
+    /// grows by `blocks_per_run` blocks between the runs, starting with a run at `current_tip`.
+    ///
+    /// Returns the highest pruned block per segment after each run, according to the segment's
+    /// prune mode and the registered consumer floors. The projection is done purely in memory,
+    /// assuming that every run prunes everything up to its target, i.e. it's not limited by the
+    /// delete limit, timeout or rate limit. Segments with nothing to prune yet or an invalid
+    /// prune mode are not included.
+    pub fn project(
+        &self,
+        current_tip: BlockNumber,
+        blocks_per_run: u64,
+        runs: usize,
+    ) -> Vec<BTreeMap<PruneSegment, BlockNumber>> {
+        let mut visitor = ProjectionVisitor {
+            tip_block_number: current_tip,
+            consumer_floor: self.consumer_floors.values().min().copied(),
+            checkpoints: BTreeMap::new(),
+        };
+        let mut projection = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            // The projection visitor never fails
+            let _ = self.segments.visit(&mut visitor);
+            projection.push(visitor.checkpoints.clone());
+            visitor.tip_block_number = visitor.tip_block_number.saturating_add(blocks_per_run);
+        }
+        projection
     }
 
     /// Run the pruner with the given provider. This will only prune data up to the highest finished
@@ -116,6 +409,17 @@ where
         &mut self,
         provider: &Provider,
         tip_block_number: BlockNumber,
+    ) -> PrunerResult {
+        self.run_with_provider_and_cancel(provider, tip_block_number, None)
+    }
+
+    /// Same as [`Self::run_with_provider`], but stops pruning before the next segment once the
+    /// `cancel` token is cancelled, returning [`PruneInterruptReason::Cancelled`].
+    fn run_with_provider_and_cancel(
+        &mut self,
+        provider: &Provider,
+        tip_block_number: BlockNumber,
+        cancel: Option<&CancellationToken>,
     ) -> PrunerResult {
         let Some(tip_block_number) =
             self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
@@ -124,25 +428,29 @@ where
         };
         if tip_block_number == 0 {
             self.previous_tip_block_number = Some(tip_block_number);
+            self.previous_run_instant = Some(Instant::now());
 
             debug!(target: "pruner", %tip_block_number, "Nothing to prune yet");
             return Ok(PruneProgress::Finished.into())
         }
 
-        self.event_sender.notify(PrunerEvent::Started { tip_block_number });
+        self.notifier.notify(PrunerEvent::Started { tip_block_number });
 
         debug!(target: "pruner", %tip_block_number, "Pruner started");
         let start = Instant::now();
 
-        let mut limiter = PruneLimiter::default().set_deleted_entries_limit(self.delete_limit);
-        if let Some(timeout) = self.timeout {
-            limiter = limiter.set_time_limit(timeout);
-        };
-
-        let (stats, deleted_entries, output) =
-            self.prune_segments(provider, tip_block_number, &mut limiter)?;
+        let mut limiter = self.limiter();
+        let (stats, deleted_entries, mut output) =
+            self.prune_segments(provider, tip_block_number, &mut limiter, cancel, false)?;
+        // The own delete limits of the pruned segments were already added by the visitor
+        output.effective_delete_limit =
+            output.effective_delete_limit.saturating_add(self.delete_limit);
+        output.delete_limit_remaining =
+            output.effective_delete_limit.saturating_sub(deleted_entries);
 
         self.previous_tip_block_number = Some(tip_block_number);
+        self.previous_run_instant = Some(Instant::now());
+        self.update_cooldown(tip_block_number, &output);
 
         let elapsed = start.elapsed();
         self.metrics.duration_seconds.record(elapsed);
@@ -163,15 +471,53 @@ where
             "{message}",
         );
 
-        self.event_sender.notify(PrunerEvent::Finished { tip_block_number, elapsed, stats });
+        self.notifier.notify(PrunerEvent::Finished {
+            tip_block_number,
+            elapsed,
+            stats,
+            budget_consumers: output.budget_consumers.clone(),
+            effective_delete_limit: output.effective_delete_limit,
+            delete_limit_remaining: output.delete_limit_remaining,
+        });
 
         Ok(output)
     }
 
+    /// Starts the cooldown if the run at `tip_block_number` finished pruning after the previous run
+    /// returned [`PruneProgress::HasMoreData`], see [`Self::with_cooldown`].
+    fn update_cooldown(&mut self, tip_block_number: BlockNumber, output: &PrunerOutput) {
+        if !output.is_finished() {
+            self.has_more_data = true;
+            self.cooldown_until = None;
+        } else if std::mem::take(&mut self.has_more_data) && self.cooldown > 0 {
+            let cooldown_until = tip_block_number.saturating_add(self.cooldown);
+            debug!(target: "pruner", %tip_block_number, %cooldown_until, "Pruner finished the remaining data, cooling down");
+            self.cooldown_until = Some(cooldown_until);
+        }
+    }
+
+    /// Returns the limiter of a single run according to the delete limit and the timeout.
+    fn limiter(&self) -> PruneLimiter {
+        let mut limiter = PruneLimiter::default().set_deleted_entries_limit(self.delete_limit);
+        if let Some(timeout) = self.timeout {
+            limiter = limiter.set_time_limit(timeout);
+        };
+        limiter
+    }
+
     /// Prunes the segments that the [Pruner] was initialized with, and the segments that needs to
     /// be pruned according to the highest `static_files`. Segments are parts of the database that
     /// represent one or more tables.
     ///
+    /// Segments are pruned sequentially within the single transaction of the `provider`. Even the
+    /// segments writing to disjoint tables can't be pruned in parallel: the database allows only
+    /// one read-write transaction at a time, and separate transactions couldn't be committed
+    /// atomically. Sharing the transaction between threads wouldn't help either, since MDBX
+    /// serializes the operations of a transaction.
+    ///
+    /// If `dry_run` is set, the rate limit isn't applied, and the checkpoints, metrics and the
+    /// state of the [Pruner] aren't updated.
+    ///
     /// Returns a list of stats per pruned segment, total number of entries pruned, and
     /// [`PruneProgress`].
     fn prune_segments(
@@ -179,92 +525,56 @@ where
         provider: &Provider,
         tip_block_number: BlockNumber,
         limiter: &mut PruneLimiter,
+        cancel: Option<&CancellationToken>,
+        dry_run: bool,
     ) -> Result<(Vec<PrunedSegmentInfo>, usize, PrunerOutput), PrunerError> {
-        let mut stats = Vec::with_capacity(self.segments.len());
-        let mut pruned = 0;
-        let mut output = PrunerOutput {
-            progress: PruneProgress::Finished,
-            segments: Vec::with_capacity(self.segments.len()),
+        let mut visitor = PruneSegmentVisitor {
+            provider,
+            tip_block_number,
+            cancel,
+            dry_run,
+            consumer_floor: self.consumer_floors.values().min().copied(),
+            rate_limiter: if dry_run { None } else { self.rate_limiter.as_mut() },
+            limiter,
+            size_target: self.size_target.as_ref(),
+            segment_delete_limits: &self.segment_delete_limits,
+            metrics: &mut self.metrics,
+            notifier: &mut self.notifier,
+            lifetime_pruned: &mut self.lifetime_pruned,
+            checkpoint_stalls: &mut self.checkpoint_stalls,
+            stats: Vec::with_capacity(self.segments.len()),
+            pruned: 0,
+            output: PrunerOutput {
+                progress: PruneProgress::Finished,
+                segments: Vec::with_capacity(self.segments.len()),
+                budget_consumers: Vec::new(),
+                oldest_retained: BTreeMap::new(),
+                effective_delete_limit: 0,
+                delete_limit_remaining: 0,
+                checkpoints_updated: false,
+                committed: false,
+            },
         };
-
-        for segment in &self.segments {
-            if limiter.is_limit_reached() {
-                break
-            }
-
-            if let Some((to_block, prune_mode)) = segment
-                .mode()
-                .map(|mode| {
-                    mode.prune_target_block(tip_block_number, segment.segment(), segment.purpose())
-                })
-                .transpose()?
-                .flatten()
-            {
-                debug!(
-                    target: "pruner",
-                    segment = ?segment.segment(),
-                    purpose = ?segment.purpose(),
-                    %to_block,
-                    ?prune_mode,
-                    "Segment pruning started"
-                );
-
-                let segment_start = Instant::now();
-                let previous_checkpoint = provider.get_prune_checkpoint(segment.segment())?;
-                let segment_output = segment.prune(
-                    provider,
-                    PruneInput { previous_checkpoint, to_block, limiter: limiter.clone() },
-                )?;
-                if let Some(checkpoint) = segment_output.checkpoint {
-                    segment
-                        .save_checkpoint(provider, checkpoint.as_prune_checkpoint(prune_mode))?;
-                }
-                self.metrics
-                    .get_prune_segment_metrics(segment.segment())
-                    .duration_seconds
-                    .record(segment_start.elapsed());
-                if let Some(highest_pruned_block) =
-                    segment_output.checkpoint.and_then(|checkpoint| checkpoint.block_number)
-                {
-                    self.metrics
-                        .get_prune_segment_metrics(segment.segment())
-                        .highest_pruned_block
-                        .set(highest_pruned_block as f64);
-                }
-
-                output.progress = segment_output.progress;
-                output.segments.push((segment.segment(), segment_output));
-
-                debug!(
-                    target: "pruner",
-                    segment = ?segment.segment(),
-                    purpose = ?segment.purpose(),
-                    %to_block,
-                    ?prune_mode,
-                    %segment_output.pruned,
-                    "Segment pruning finished"
-                );
-
-                if segment_output.pruned > 0 {
-                    limiter.increment_deleted_entries_count_by(segment_output.pruned);
-                    pruned += segment_output.pruned;
-                    let info = PrunedSegmentInfo {
-                        segment: segment.segment(),
-                        pruned: segment_output.pruned,
-                        progress: segment_output.progress,
-                    };
-                    stats.push(info);
-                }
-            } else {
-                debug!(target: "pruner", segment = ?segment.segment(), purpose = ?segment.purpose(), "Nothing to prune for the segment");
+        if self.round_robin_ordering {
+            let first_segment = self.first_segment;
+            self.segments.visit_from(first_segment, &mut visitor)?;
+            if !dry_run {
+                self.first_segment = (first_segment + 1) % self.segments.len().max(1);
             }
+        } else {
+            self.segments.visit(&mut visitor)?;
         }
 
+        let PruneSegmentVisitor { stats, pruned, mut output, .. } = visitor;
+        output.budget_consumers = PrunedSegmentInfo::budget_consumers(&stats);
+
         Ok((stats, pruned, output))
     }
 
     /// Returns `true` if the pruning is needed at the provided tip block number.
-    /// This determined by the check against minimum pruning interval and last pruned block number.
+    /// This determined by the check against minimum pruning interval and last pruned block number,
+    /// and, if set, the minimum time interval and the time of the previous run, and the cooldown,
+    /// see [`Pruner::with_cooldown`].
     pub fn is_pruning_needed(&self, tip_block_number: BlockNumber) -> bool {
         let Some(tip_block_number) =
             self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
@@ -272,6 +582,21 @@ where
             return false
         };
 
+        if self.cooldown_until.is_some_and(|cooldown_until| tip_block_number < cooldown_until) {
+            trace!(target: "pruner", %tip_block_number, cooldown_until = ?self.cooldown_until, "Pruner is cooling down");
+            return false
+        }
+
+        if let (Some(min_time_interval), Some(previous_run_instant)) =
+            (self.min_time_interval, self.previous_run_instant)
+        {
+            let elapsed = previous_run_instant.elapsed();
+            if elapsed < min_time_interval {
+                trace!(target: "pruner", ?elapsed, ?min_time_interval, "Minimum pruning time interval not reached");
+                return false
+            }
+        }
+
         // Saturating subtraction is needed for the case when the chain was reverted, meaning
         // current block number might be less than the previous tip block number.
         // If that's the case, no pruning is needed as outdated data is also reverted.
@@ -315,66 +640,1382 @@ where
     }
 }
 
-impl<PF> Pruner<PF::ProviderRW, PF>
+impl<PF, S> Pruner<PF::ProviderRW, PF, S>
 where
     PF: DatabaseProviderFactory<ProviderRW: PruneCheckpointWriter + PruneCheckpointReader>,
+    S: Segments<PF::ProviderRW>,
 {
+    /// Maximum number of runs made by [`Self::run_to_completion`].
+    pub const MAX_RUNS_TO_COMPLETION: usize = 10_000;
+
     /// Run the pruner. This will only prune data up to the highest finished ExEx height, if there
     /// are no ExExes.
     ///
     /// Returns a [`PruneProgress`], indicating whether pruning is finished, or there is more data
-    /// to prune.
+    /// to prune. The database transaction is only committed if any entries were pruned or any
+    /// checkpoints were updated, see [`PrunerOutput::committed`].
     pub fn run(&mut self, tip_block_number: BlockNumber) -> PrunerResult {
+        self.run_cancellable(tip_block_number, None)
+    }
+
+    /// Same as [`Self::run`], but checks the `cancel` token before pruning each segment and
+    /// before committing, so the node can shut down without waiting for the whole run.
+    ///
+    /// Checkpoints of the segments pruned before the cancellation are still committed, and
+    /// [`PruneProgress::HasMoreData`] with [`PruneInterruptReason::Cancelled`] is returned, so the
+    /// next run resumes from them.
+    pub fn run_with_cancel(
+        &mut self,
+        tip_block_number: BlockNumber,
+        cancel: &CancellationToken,
+    ) -> PrunerResult {
+        self.run_cancellable(tip_block_number, Some(cancel))
+    }
+
+    fn run_cancellable(
+        &mut self,
+        tip_block_number: BlockNumber,
+        cancel: Option<&CancellationToken>,
+    ) -> PrunerResult {
+        // The previous tip block number isn't updated, so pruning resumes as soon as it's needed
+        if self.control.is_paused() {
+            debug!(target: "pruner", %tip_block_number, "Pruner is paused");
+            return Ok(PruneProgress::Finished.into())
+        }
+
+        let acquire_start = Instant::now();
         let provider = self.provider_factory.database_provider_rw()?;
-        let result = self.run_with_provider(&provider, tip_block_number);
+        self.metrics.provider_acquire_seconds.record(acquire_start.elapsed());
+
+        let mut result = self.run_with_provider_and_cancel(&provider, tip_block_number, cancel);
+
+        // Nothing was written, so the transaction is dropped without committing
+        if matches!(&result, Ok(output) if !output.has_changes()) {
+            debug!(target: "pruner", %tip_block_number, "Nothing was pruned and no checkpoints were updated, skipping commit");
+            return result
+        }
+
+        // The checkpoints of the segments pruned before a cancellation are committed as well, so
+        // their progress isn't lost
+        if cancel.is_some_and(|cancel| cancel.is_cancelled()) {
+            debug!(target: "pruner", %tip_block_number, "Pruner run cancelled, committing pruned segments");
+        }
+
         provider.commit()?;
+        if let Ok(output) = &mut result {
+            output.committed = true;
+        }
         result
     }
+
+    /// Counts the entries that [`Self::run`] would prune at the given tip, without modifying the
+    /// database.
+    ///
+    /// The segments walk the entries to prune within the delete limit and the timeout as usual,
+    /// but only count them instead of deleting, so the returned stats contain the hypothetical
+    /// deletions. The segments are still typed over the read-write provider, so the database
+    /// transaction is opened for writing, but it's never committed. The rate limit isn't applied,
+    /// and neither checkpoints, metrics, events nor the state of the [Pruner] are updated.
+    pub fn dry_run(
+        &mut self,
+        tip_block_number: BlockNumber,
+    ) -> Result<Vec<PrunedSegmentInfo>, PrunerError> {
+        let Some(tip_block_number) =
+            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
+        else {
+            return Ok(Vec::new())
+        };
+
+        // Nothing is written, and the provider is dropped without committing
+        let provider = self.provider_factory.database_provider_rw()?;
+        let mut limiter = self.limiter();
+        let (stats, deleted_entries, _) =
+            self.prune_segments(&provider, tip_block_number, &mut limiter, None, true)?;
+
+        debug!(target: "pruner", %tip_block_number, %deleted_entries, ?stats, "Pruner dry run finished");
+        Ok(stats)
+    }
+
+    /// Run the pruner repeatedly until all segments are fully pruned up to the given tip, or
+    /// [`Self::MAX_RUNS_TO_COMPLETION`] runs were made.
+    ///
+    /// Each run respects the configured `delete_limit` and is committed separately, so the size of
+    /// a single database transaction stays bounded.
+    ///
+    /// Returns the [`PrunerOutput`] of the last run.
+    pub fn run_to_completion(&mut self, tip_block_number: BlockNumber) -> PrunerResult {
+        let mut runs = 0;
+        loop {
+            let output = self.run(tip_block_number)?;
+            runs += 1;
+
+            let finished = output.is_finished();
+            if finished || runs >= Self::MAX_RUNS_TO_COMPLETION {
+                debug!(target: "pruner", %tip_block_number, %runs, %finished, "Pruner run to completion finished");
+                return Ok(output)
+            }
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Pruner;
-    use reth_exex_types::FinishedExExHeight;
-    use reth_provider::test_utils::create_test_provider_factory;
+/// Prunes every visited segment within the limits of the [`PruneLimiter`], collecting the output
+/// of the [`Pruner`] run.
+struct PruneSegmentVisitor<'a, Provider> {
+    provider: &'a Provider,
+    tip_block_number: BlockNumber,
+    cancel: Option<&'a CancellationToken>,
+    /// Whether the pruning is only counted, see [`Pruner::dry_run`].
+    dry_run: bool,
+    /// Lowest registered consumer floor, see [`Pruner::register_consumer_floor`].
+    consumer_floor: Option<BlockNumber>,
+    rate_limiter: Option<&'a mut DeletionRateLimiter>,
+    limiter: &'a mut PruneLimiter,
+    /// Maximum size of the database, see [`Pruner::with_size_target`].
+    size_target: Option<&'a DatabaseSizeTarget>,
+    /// Delete limits of the segments that don't share the delete limit of the run, see
+    /// [`Pruner::with_segment_delete_limits`].
+    segment_delete_limits: &'a BTreeMap<PruneSegment, usize>,
+    metrics: &'a mut Metrics,
+    notifier: &'a mut PrunerEventNotifier,
+    lifetime_pruned: &'a mut BTreeMap<PruneSegment, u64>,
+    checkpoint_stalls: &'a mut BTreeMap<PruneSegment, u64>,
+    stats: Vec<PrunedSegmentInfo>,
+    pruned: usize,
+    output: PrunerOutput,
+}
 
-    #[test]
-    fn is_pruning_needed() {
-        let provider_factory = create_test_provider_factory();
+impl<Provider> SegmentVisitor<Provider> for PruneSegmentVisitor<'_, Provider>
+where
+    Provider: PruneCheckpointReader + PruneCheckpointWriter,
+{
+    fn visit<S: Segment<Provider> + ?Sized>(&mut self, segment: &S) -> Result<bool, PrunerError> {
+        if self.limiter.is_time_limit_reached() {
+            return Ok(false)
+        }
 
-        let (finished_exex_height_tx, finished_exex_height_rx) =
-            tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        if let Some(size_target) = self.size_target {
+            if size_target.is_reached()? {
+                debug!(target: "pruner", segment = ?segment.segment(), target_size = %size_target.target_size, "Database is within the size target");
+                return Ok(false)
+            }
+        }
 
-        let mut pruner =
-            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx);
+        let segment_delete_limit = self.segment_delete_limits.get(&segment.segment()).copied();
+        if segment_delete_limit.is_none() && self.limiter.is_deleted_entries_limit_reached() {
+            // Segments with their own delete limit can still be pruned after the shared one is
+            // exhausted
+            return Ok(!self.segment_delete_limits.is_empty())
+        }
 
-        // No last pruned block number was set before
-        let first_block_number = 1;
-        assert!(!pruner.is_pruning_needed(first_block_number));
-        pruner.previous_tip_block_number = Some(first_block_number);
+        if self.cancel.is_some_and(|cancel| cancel.is_cancelled()) {
+            debug!(target: "pruner", segment = ?segment.segment(), "Pruner run cancelled");
+            self.output.progress = PruneProgress::HasMoreData(PruneInterruptReason::Cancelled);
+            return Ok(false)
+        }
 
-        // Tip block number delta is >= than min block interval
-        let second_block_number = first_block_number + pruner.min_block_interval as u64;
-        assert!(pruner.is_pruning_needed(second_block_number));
-        pruner.previous_tip_block_number = Some(second_block_number);
+        let Some((to_block, prune_mode)) = segment
+            .mode()
+            .map(|mode| {
+                mode.prune_target_block(self.tip_block_number, segment.segment(), segment.purpose())
+            })
+            .transpose()?
+            .flatten()
+        else {
+            debug!(target: "pruner", segment = ?segment.segment(), purpose = ?segment.purpose(), "Nothing to prune for the segment");
+            return Ok(true)
+        };
 
-        // Tip block number delta is < than min block interval
-        assert!(!pruner.is_pruning_needed(second_block_number));
+        // Don't prune the data that downstream consumers still need
+        let to_block = match self.consumer_floor {
+            Some(consumer_floor) if segment.purpose().is_user() => {
+                let Some(max_block) = consumer_floor.checked_sub(1) else {
+                    debug!(target: "pruner", segment = ?segment.segment(), purpose = ?segment.purpose(), %consumer_floor, "Nothing to prune for the segment below the consumer floor");
+                    return Ok(true)
+                };
+                to_block.min(max_block)
+            }
+            _ => to_block,
+        };
 
-        // Tip block number delta is >= than min block interval
-        let third_block_number = second_block_number + pruner.min_block_interval as u64;
-        assert!(pruner.is_pruning_needed(third_block_number));
+        debug!(
+            target: "pruner",
+            segment = ?segment.segment(),
+            purpose = ?segment.purpose(),
+            %to_block,
+            ?prune_mode,
+            "Segment pruning started"
+        );
 
-        // Not all ExExs have emitted a `FinishedHeight` event yet
-        finished_exex_height_tx.send(FinishedExExHeight::NotReady).unwrap();
-        assert!(!pruner.is_pruning_needed(third_block_number));
+        // Wait for the rate limiter to allow the next batch of deleted entries
+        let batch_size = match self.rate_limiter.as_deref_mut() {
+            Some(rate_limiter) => {
+                let limit_left =
+                    segment_delete_limit.or_else(|| self.limiter.deleted_entries_limit_left());
+                let batch_size = rate_limiter.batch_size(limit_left);
+                let wait_time = rate_limiter.wait_time(batch_size);
+                if self.limiter.time_limit_left().is_some_and(|time_left| time_left < wait_time) {
+                    debug!(target: "pruner", segment = ?segment.segment(), ?wait_time, "Run timeout would be reached while waiting for the rate limiter");
+                    self.output.progress =
+                        PruneProgress::HasMoreData(PruneInterruptReason::Timeout);
+                    return Ok(false)
+                }
+                if !wait_time.is_zero() {
+                    trace!(target: "pruner", segment = ?segment.segment(), ?wait_time, %batch_size, "Waiting for the rate limiter");
+                    std::thread::sleep(wait_time);
+                }
+                rate_limiter.acquire(batch_size);
+                Some(batch_size)
+            }
+            None => None,
+        };
+        let limiter = match segment_delete_limit {
+            Some(limit) => self.limiter.with_own_deleted_entries_limit(limit),
+            None => self.limiter.clone(),
+        };
+        // Limit the segment to its weighted share of the entries left to delete
+        let share = limiter
+            .deleted_entries_limit_left()
+            .map(|limit_left| weighted_share(limit_left, segment.catchup_weight()));
+        let limiter = match batch_size.into_iter().chain(share).min() {
+            Some(entries) => limiter.batch(entries),
+            None => limiter,
+        };
 
-        // Adjust tip block number to the finished ExEx height that doesn't reach the threshold
-        finished_exex_height_tx.send(FinishedExExHeight::Height(second_block_number)).unwrap();
-        assert!(!pruner.is_pruning_needed(third_block_number));
+        let segment_start = Instant::now();
+        let previous_checkpoint = self.provider.get_prune_checkpoint(segment.segment())?;
+        if !self.dry_run {
+            self.notifier.notify(PrunerEvent::SegmentStarted {
+                segment: segment.segment(),
+                to_block,
+                previous_checkpoint: previous_checkpoint
+                    .and_then(|checkpoint| checkpoint.block_number),
+            });
+        }
+        if let Some(limit) = segment_delete_limit {
+            self.output.effective_delete_limit =
+                self.output.effective_delete_limit.saturating_add(limit);
+        }
+        let input = PruneInput { previous_checkpoint, to_block, limiter, dry_run: self.dry_run };
+        let segment_output = match segment.prune_range(self.provider, &input)? {
+            Some(segment_output) => segment_output,
+            None => segment.prune(self.provider, input)?,
+        };
+        if let (Some(rate_limiter), Some(batch_size)) =
+            (self.rate_limiter.as_deref_mut(), batch_size)
+        {
+            rate_limiter.release(batch_size.saturating_sub(segment_output.pruned));
+        }
+        if let Some(checkpoint) = segment_output.checkpoint.filter(|_| !self.dry_run) {
+            let prune_checkpoint = checkpoint.as_prune_checkpoint(prune_mode);
+            segment.save_checkpoint(self.provider, prune_checkpoint)?;
+            self.output.checkpoints_updated |= previous_checkpoint != Some(prune_checkpoint);
 
-        // Adjust tip block number to the finished ExEx height that reaches the threshold
-        finished_exex_height_tx.send(FinishedExExHeight::Height(third_block_number)).unwrap();
-        assert!(pruner.is_pruning_needed(third_block_number));
+            // A finished segment that deleted entries must have advanced its checkpoint block,
+            // otherwise the same range is pruned again on the next run
+            let previous_block = previous_checkpoint.and_then(|checkpoint| checkpoint.block_number);
+            if segment_output.progress.is_finished() &&
+                segment_output.pruned > 0 &&
+                checkpoint.block_number <= previous_block
+            {
+                error!(
+                    target: "pruner",
+                    segment = ?segment.segment(),
+                    ?previous_block,
+                    checkpoint_block = ?checkpoint.block_number,
+                    pruned = %segment_output.pruned,
+                    "Segment pruned entries without advancing its checkpoint"
+                );
+                self.metrics
+                    .get_prune_segment_metrics(segment.segment())
+                    .checkpoint_stall
+                    .increment(1);
+                *self.checkpoint_stalls.entry(segment.segment()).or_default() += 1;
+            }
+        }
+        let checkpoint_block = segment_output
+            .checkpoint
+            .and_then(|checkpoint| checkpoint.block_number)
+            .or_else(|| previous_checkpoint.and_then(|checkpoint| checkpoint.block_number));
+        if let Some(checkpoint_block) = checkpoint_block {
+            self.output.oldest_retained.insert(segment.segment(), checkpoint_block + 1);
+        }
+        let pruned_blocks = segment_output.pruned_blocks.unwrap_or_else(|| {
+            // Derive from the checkpoints, if the segment doesn't track pruned blocks
+            let first_block = previous_checkpoint
+                .and_then(|checkpoint| checkpoint.block_number)
+                .map_or(0, |block_number| block_number + 1);
+            segment_output
+                .checkpoint
+                .and_then(|checkpoint| checkpoint.block_number)
+                .map_or(0, |last_block| (last_block + 1).saturating_sub(first_block))
+        });
+        if !self.dry_run {
+            let segment_metrics = self.metrics.get_prune_segment_metrics(segment.segment());
+            segment_metrics.duration_seconds.record(segment_start.elapsed());
+            segment_metrics.pruned_rows.increment(segment_output.pruned as u64);
+            segment_metrics.pruned_blocks.increment(pruned_blocks);
+            if let Some(highest_pruned_block) =
+                segment_output.checkpoint.and_then(|checkpoint| checkpoint.block_number)
+            {
+                self.metrics
+                    .get_prune_segment_metrics(segment.segment())
+                    .highest_pruned_block
+                    .set(highest_pruned_block as f64);
+            }
+
+            self.notifier.notify(PrunerEvent::SegmentFinished {
+                segment: segment.segment(),
+                pruned: segment_output.pruned,
+                done: segment_output.progress.is_finished(),
+                percent_complete: percent_complete(checkpoint_block, to_block),
+            });
+        }
+
+        self.output.progress = segment_output.progress;
+        self.output.segments.push((segment.segment(), segment_output));
+
+        debug!(
+            target: "pruner",
+            segment = ?segment.segment(),
+            purpose = ?segment.purpose(),
+            %to_block,
+            ?prune_mode,
+            %segment_output.pruned,
+            %pruned_blocks,
+            "Segment pruning finished"
+        );
+
+        if segment_output.pruned > 0 {
+            self.limiter.increment_deleted_entries_count_by(segment_output.pruned);
+            self.pruned += segment_output.pruned;
+            if !self.dry_run {
+                *self.lifetime_pruned.entry(segment.segment()).or_default() +=
+                    segment_output.pruned as u64;
+            }
+            let info = PrunedSegmentInfo {
+                segment: segment.segment(),
+                pruned: segment_output.pruned,
+                progress: segment_output.progress,
+                delete_limit: segment_delete_limit,
+            };
+            self.stats.push(info);
+        }
+
+        Ok(true)
+    }
+}
+
+/// Returns the number of entries out of `limit_left` that a segment with the catch-up `weight` may
+/// delete, see [`Segment::catchup_weight`]. The weight is clamped to `0.0..=1.0`.
+fn weighted_share(limit_left: usize, weight: f64) -> usize {
+    (limit_left as f64 * weight.clamp(0.0, 1.0)) as usize
+}
+
+/// Returns the rough share of the blocks up to `to_block` that are pruned according to the
+/// `checkpoint_block`, in percent.
+fn percent_complete(checkpoint_block: Option<BlockNumber>, to_block: BlockNumber) -> u8 {
+    let blocks = to_block as u128 + 1;
+    let pruned_blocks = checkpoint_block.map_or(0, |block| block as u128 + 1).min(blocks);
+    (pruned_blocks * 100 / blocks) as u8
+}
+
+/// Advances the projected checkpoints of the visited segments to their prune targets at the tip,
+/// see [`Pruner::project`].
+struct ProjectionVisitor {
+    tip_block_number: BlockNumber,
+    /// Lowest registered consumer floor, see [`Pruner::register_consumer_floor`].
+    consumer_floor: Option<BlockNumber>,
+    checkpoints: BTreeMap<PruneSegment, BlockNumber>,
+}
+
+impl<Provider> SegmentVisitor<Provider> for ProjectionVisitor {
+    fn visit<S: Segment<Provider> + ?Sized>(&mut self, segment: &S) -> Result<bool, PrunerError> {
+        let Some(Ok(Some((to_block, _)))) = segment.mode().map(|mode| {
+            mode.prune_target_block(self.tip_block_number, segment.segment(), segment.purpose())
+        }) else {
+            return Ok(true)
+        };
+
+        let to_block = match self.consumer_floor {
+            Some(consumer_floor) if segment.purpose().is_user() => {
+                let Some(max_block) = consumer_floor.checked_sub(1) else { return Ok(true) };
+                to_block.min(max_block)
+            }
+            _ => to_block,
+        };
+
+        let checkpoint = self.checkpoints.entry(segment.segment()).or_insert(to_block);
+        *checkpoint = (*checkpoint).max(to_block);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        segments::{PruneInput, Segment, SenderRecovery, TableSizes, TransactionLookup},
+        Pruner, PrunerError, PrunerEvent, StaticPruner,
+    };
+    use alloy_primitives::{BlockNumber, B256};
+    use assert_matches::assert_matches;
+    use reth_db::{table::Table, tables};
+    use reth_exex_types::FinishedExExHeight;
+    use reth_provider::{
+        test_utils::create_test_provider_factory, DatabaseProviderFactory, PruneCheckpointReader,
+        StatsReader,
+    };
+    use reth_prune_types::{
+        PruneInterruptReason, PruneMode, PruneProgress, PrunePurpose, PruneSegment, SegmentOutput,
+        SegmentOutputCheckpoint,
+    };
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::{
+        collections::BTreeMap,
+        num::NonZeroUsize,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+    use tokio_util::sync::CancellationToken;
+
+    #[test]
+    fn is_pruning_needed() {
+        let provider_factory = create_test_provider_factory();
+
+        let (finished_exex_height_tx, finished_exex_height_rx) =
+            tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+
+        let mut pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx);
+
+        // No last pruned block number was set before
+        let first_block_number = 1;
+        assert!(!pruner.is_pruning_needed(first_block_number));
+        pruner.previous_tip_block_number = Some(first_block_number);
+
+        // Tip block number delta is >= than min block interval
+        let second_block_number = first_block_number + pruner.min_block_interval as u64;
+        assert!(pruner.is_pruning_needed(second_block_number));
+        pruner.previous_tip_block_number = Some(second_block_number);
+
+        // Tip block number delta is < than min block interval
+        assert!(!pruner.is_pruning_needed(second_block_number));
+
+        // Tip block number delta is >= than min block interval
+        let third_block_number = second_block_number + pruner.min_block_interval as u64;
+        assert!(pruner.is_pruning_needed(third_block_number));
+
+        // Not all ExExs have emitted a `FinishedHeight` event yet
+        finished_exex_height_tx.send(FinishedExExHeight::NotReady).unwrap();
+        assert!(!pruner.is_pruning_needed(third_block_number));
+
+        // Adjust tip block number to the finished ExEx height that doesn't reach the threshold
+        finished_exex_height_tx.send(FinishedExExHeight::Height(second_block_number)).unwrap();
+        assert!(!pruner.is_pruning_needed(third_block_number));
+
+        // Adjust tip block number to the finished ExEx height that reaches the threshold
+        finished_exex_height_tx.send(FinishedExExHeight::Height(third_block_number)).unwrap();
+        assert!(pruner.is_pruning_needed(third_block_number));
+    }
+
+    #[test]
+    fn is_pruning_needed_with_min_time_interval() {
+        let provider_factory = create_test_provider_factory();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let interval = Duration::from_secs(60 * 60);
+        let mut pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx)
+                .with_min_time_interval(interval);
+
+        // No previous run, only the block interval is checked
+        assert!(pruner.is_pruning_needed(5));
+
+        // Block interval is reached, but the time interval isn't
+        pruner.previous_tip_block_number = Some(5);
+        pruner.previous_run_instant = Some(Instant::now());
+        assert!(!pruner.is_pruning_needed(10));
+
+        // Both intervals are reached
+        pruner.previous_run_instant = Instant::now().checked_sub(interval);
+        assert!(pruner.is_pruning_needed(10));
+
+        // Time interval is reached, but the block interval isn't
+        assert!(!pruner.is_pruning_needed(9));
+    }
+
+    #[test]
+    fn cooldown() {
+        let db = test_db_with_transaction_senders(30);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            1,
+            15,
+            None,
+            finished_exex_height_rx,
+        )
+        .with_cooldown(5);
+
+        // A run finishing without a backlog doesn't start the cooldown
+        assert!(pruner.run(5).unwrap().progress.is_finished());
+        assert!(pruner.is_pruning_needed(6));
+
+        // Pruning the 10 new blocks, 2 senders per block, takes two runs
+        assert!(!pruner.run(15).unwrap().progress.is_finished());
+        assert!(pruner.run(15).unwrap().progress.is_finished());
+        for tip_block_number in 16..20 {
+            assert!(!pruner.is_pruning_needed(tip_block_number));
+        }
+        assert!(pruner.is_pruning_needed(20));
+
+        // A new backlog resets the cooldown
+        assert!(!pruner.run(30).unwrap().progress.is_finished());
+        assert!(pruner.is_pruning_needed(31));
+    }
+
+    #[test]
+    fn suggest_delete_limit() {
+        let provider_factory = create_test_provider_factory();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx);
+
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 10, None), 100);
+        // Rounded up to drain the backlog within the target runs
+        assert_eq!(pruner.suggest_delete_limit(1001, 100, 10, None), 101);
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 0, None), 1000);
+        assert_eq!(pruner.suggest_delete_limit(0, 100, 10, None), 0);
+
+        // The block bound allows draining the backlog in the target runs
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 10, Some(10)), 100);
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 10, Some(50)), 100);
+        // The block bound requires 20 runs, so the limit is spread over them
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 10, Some(5)), 50);
+        assert_eq!(pruner.suggest_delete_limit(1000, 100, 10, Some(0)), 10);
+    }
+
+    /// Creates a database with random blocks up to `tip` and senders of their transactions.
+    fn test_db_with_transaction_senders(tip: BlockNumber) -> TestStageDB {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=tip,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut transaction_senders = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                transaction_senders.push((
+                    transaction_senders.len() as u64,
+                    transaction.recover_signer().expect("recover signer"),
+                ));
+            }
+        }
+        db.insert_transaction_senders(transaction_senders).expect("insert transaction senders");
+
+        db
+    }
+
+    /// The delete budget is applied per run and isn't scaled by the number of blocks the chain
+    /// advanced since the previous run, including when the chain was reverted.
+    #[test]
+    fn delete_limit_per_run() {
+        let db = test_db_with_transaction_senders(50);
+
+        let delete_limit = 5;
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            delete_limit,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // Small and large gaps between the tips, and a reverted chain with the tip below the
+        // previous one.
+        for tip_block_number in [10, 11, 40, 20] {
+            let output = pruner.run(tip_block_number).unwrap();
+            assert!(!output.progress.is_finished());
+            assert_eq!(
+                output.segments.iter().map(|(_, segment)| segment.pruned).sum::<usize>(),
+                delete_limit,
+                "unexpected number of pruned entries at tip {tip_block_number}"
+            );
+            // Every run is bound by the delete limit
+            assert_eq!(output.effective_delete_limit, delete_limit);
+            assert_eq!(output.delete_limit_remaining, 0);
+        }
+        assert_eq!(
+            pruner.lifetime_pruned().get(&PruneSegment::SenderRecovery),
+            Some(&(4 * delete_limit as u64))
+        );
+    }
+
+    #[test]
+    fn segment_delete_limits() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![
+                Box::new(TransactionLookup::new(PruneMode::Full)),
+                Box::new(SenderRecovery::new(PruneMode::Full)),
+            ],
+            0,
+            0,
+            None,
+            finished_exex_height_rx,
+        )
+        .with_segment_delete_limits(BTreeMap::from([(PruneSegment::SenderRecovery, 3)]));
+
+        // The shared delete limit is exhausted, but the sender recovery has its own one
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.segments.len(), 1);
+        assert_eq!(output.segments[0].0, PruneSegment::SenderRecovery);
+        assert_eq!(output.segments[0].1.pruned, 3);
+
+        assert_matches!(
+            pruner.take_last_event(),
+            Some(PrunerEvent::Finished { stats, .. })
+                if stats.len() == 1 && stats[0].pruned == 3 && stats[0].delete_limit == Some(3)
+        );
+
+        // The run could delete the entries of the sender recovery's own delete limit only
+        assert_eq!(output.effective_delete_limit, 3);
+        assert_eq!(output.delete_limit_remaining, 0);
+    }
+
+    #[test]
+    fn segment_delete_limit_below_shared_deletions() {
+        let db = test_db_with_transaction_senders(10);
+        db.insert_tx_hash_numbers(
+            db.table::<tables::Transactions>()
+                .unwrap()
+                .into_iter()
+                .map(|(tx_number, transaction)| (transaction.hash(), tx_number)),
+        )
+        .unwrap();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![
+                Box::new(TransactionLookup::new(PruneMode::Full)),
+                Box::new(SenderRecovery::new(PruneMode::Full)),
+            ],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        )
+        .with_segment_delete_limits(BTreeMap::from([(PruneSegment::SenderRecovery, 3)]));
+
+        // The transaction lookup prunes more entries than the own delete limit of the sender
+        // recovery, which still gets its whole limit
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.segments.len(), 2);
+        assert_eq!(output.segments[0].0, PruneSegment::TransactionLookup);
+        assert_eq!(output.segments[0].1.pruned, 20);
+        assert_eq!(output.segments[1].0, PruneSegment::SenderRecovery);
+        assert_eq!(output.segments[1].1.pruned, 3);
+
+        assert_eq!(output.effective_delete_limit, 103);
+        assert_eq!(output.delete_limit_remaining, 80);
+    }
+
+    #[test]
+    fn dry_run() {
+        let db = test_db_with_transaction_senders(10);
+        let senders = db.table::<tables::TransactionSenders>().unwrap().len();
+
+        let delete_limit = 5;
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            delete_limit,
+            None,
+            finished_exex_height_rx,
+        );
+
+        let stats = pruner.dry_run(10).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].segment, PruneSegment::SenderRecovery);
+        assert_eq!(stats[0].pruned, delete_limit);
+
+        // Nothing was deleted and the pruner state wasn't updated
+        assert_eq!(db.table::<tables::TransactionSenders>().unwrap().len(), senders);
+        assert!(pruner.lifetime_pruned().is_empty());
+        assert_eq!(pruner.previous_tip_block_number, None);
+
+        // The real run prunes the same entries
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.segments[0].1.pruned, delete_limit);
+        assert_eq!(db.table::<tables::TransactionSenders>().unwrap().len(), senders - delete_limit);
+    }
+
+    #[test]
+    fn take_last_event() {
+        let provider_factory = create_test_provider_factory();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            provider_factory,
+            vec![],
+            5,
+            10,
+            None,
+            finished_exex_height_rx,
+        );
+        assert_eq!(pruner.take_last_event(), None);
+
+        pruner.run(10).unwrap();
+        assert_matches!(
+            pruner.take_last_event(),
+            Some(PrunerEvent::Finished {
+                tip_block_number: 10,
+                stats,
+                budget_consumers,
+                effective_delete_limit: 10,
+                delete_limit_remaining: 10,
+                ..
+            }) if stats.is_empty() && budget_consumers.is_empty()
+        );
+        // The event was taken
+        assert_eq!(pruner.take_last_event(), None);
+    }
+
+    #[test]
+    fn event_sink() {
+        let provider_factory = create_test_provider_factory();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx);
+
+        let (sink, mut events) = tokio::sync::broadcast::channel(16);
+        pruner.set_event_sink(sink);
+
+        pruner.run(10).unwrap();
+        assert_eq!(events.try_recv(), Ok(PrunerEvent::Started { tip_block_number: 10 }));
+        assert_matches!(
+            events.try_recv(),
+            Ok(PrunerEvent::Finished { tip_block_number: 10, stats, budget_consumers, .. })
+                if stats.is_empty() && budget_consumers.is_empty()
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn segment_events() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            5,
+            None,
+            finished_exex_height_rx,
+        );
+
+        let (sink, mut events) = tokio::sync::broadcast::channel(64);
+        pruner.set_event_sink(sink);
+
+        pruner.run(10).unwrap();
+        assert_eq!(events.try_recv(), Ok(PrunerEvent::Started { tip_block_number: 10 }));
+        assert_eq!(
+            events.try_recv(),
+            Ok(PrunerEvent::SegmentStarted {
+                segment: PruneSegment::SenderRecovery,
+                to_block: 10,
+                previous_checkpoint: None
+            })
+        );
+        assert_matches!(
+            events.try_recv(),
+            Ok(PrunerEvent::SegmentFinished {
+                segment: PruneSegment::SenderRecovery,
+                pruned: 5,
+                done: false,
+                percent_complete
+            }) if percent_complete < 100
+        );
+        assert_matches!(events.try_recv(), Ok(PrunerEvent::Finished { .. }));
+
+        // Nothing is left to prune after the run to completion
+        pruner.run_to_completion(10).unwrap();
+        let last_segment_event = std::iter::from_fn(|| events.try_recv().ok())
+            .filter(|event| matches!(event, PrunerEvent::SegmentFinished { .. }))
+            .last();
+        assert_matches!(
+            last_segment_event,
+            Some(PrunerEvent::SegmentFinished { done: true, percent_complete: 100, .. })
+        );
+    }
+
+    #[test]
+    fn static_pruner() {
+        let db = test_db_with_transaction_senders(10);
+
+        let delete_limit = 5;
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = StaticPruner::new_static_with_factory(
+            db.factory.clone(),
+            (SenderRecovery::new(PruneMode::Full), TransactionLookup::new(PruneMode::Full)),
+            0,
+            delete_limit,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // Senders use up the whole delete budget, so transaction lookup isn't visited
+        let output = pruner.run(10).unwrap();
+        assert!(!output.progress.is_finished());
+        assert_matches!(
+            output.segments.as_slice(),
+            [(PruneSegment::SenderRecovery, segment)] if segment.pruned == delete_limit
+        );
+
+        let output = pruner.run_to_completion(10).unwrap();
+        assert!(output.progress.is_finished());
+        assert_eq!(
+            pruner.lifetime_pruned().get(&PruneSegment::SenderRecovery),
+            Some(&(db.table::<tables::Transactions>().unwrap().len() as u64))
+        );
+        assert!(db.table::<tables::TransactionSenders>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn consumer_floor() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // Consumer still needs the genesis block
+        pruner.register_consumer_floor("indexer", 0);
+        let output = pruner.run(10).unwrap();
+        assert!(output.segments.is_empty());
+
+        // The lowest floor is respected
+        pruner.register_consumer_floor("indexer", 5);
+        pruner.register_consumer_floor("exporter", 3);
+        let output = pruner.run(10).unwrap();
+        assert_matches!(
+            output.segments.as_slice(),
+            [(PruneSegment::SenderRecovery, segment)]
+                if segment.checkpoint.and_then(|checkpoint| checkpoint.block_number) == Some(2)
+        );
+
+        // Consumer advanced its floor
+        pruner.register_consumer_floor("exporter", 8);
+        let output = pruner.run(10).unwrap();
+        assert_matches!(
+            output.segments.as_slice(),
+            [(PruneSegment::SenderRecovery, segment)]
+                if segment.checkpoint.and_then(|checkpoint| checkpoint.block_number) == Some(4)
+        );
+    }
+
+    #[test]
+    fn project() {
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let pruner = Pruner::new_with_factory(
+            create_test_provider_factory(),
+            vec![
+                Box::new(SenderRecovery::new(PruneMode::Full)),
+                Box::new(TransactionLookup::new(PruneMode::Distance(64))),
+            ],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        assert_eq!(
+            pruner.project(50, 30, 3),
+            vec![
+                BTreeMap::from([(PruneSegment::SenderRecovery, 50)]),
+                BTreeMap::from([
+                    (PruneSegment::SenderRecovery, 80),
+                    (PruneSegment::TransactionLookup, 16)
+                ]),
+                BTreeMap::from([
+                    (PruneSegment::SenderRecovery, 110),
+                    (PruneSegment::TransactionLookup, 46)
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn round_robin_ordering() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![
+                Box::new(SenderRecovery::new(PruneMode::Full)),
+                Box::new(TransactionLookup::new(PruneMode::Full)),
+            ],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        )
+        .with_round_robin_ordering(true);
+
+        // The first pruned segment rotates on every run
+        let first_segments = (0..3)
+            .map(|_| pruner.run(10).unwrap().segments.first().map(|(segment, _)| *segment))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            first_segments,
+            vec![
+                Some(PruneSegment::SenderRecovery),
+                Some(PruneSegment::TransactionLookup),
+                Some(PruneSegment::SenderRecovery)
+            ]
+        );
+    }
+
+    #[test]
+    fn run_with_cancel() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // No segments are pruned after the cancellation
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let output = pruner.run_with_cancel(10, &cancel).unwrap();
+        assert_eq!(output.progress, PruneProgress::HasMoreData(PruneInterruptReason::Cancelled));
+        assert!(output.segments.is_empty());
+        assert!(!output.committed);
+        assert_eq!(pruner.previous_tip_block_number, Some(10));
+
+        // The next run resumes pruning
+        let output = pruner.run_with_cancel(10, &CancellationToken::new()).unwrap();
+        assert_eq!(output.progress, PruneProgress::Finished);
+        assert!(output.committed);
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+        let control = pruner.control_handle();
+
+        control.pause();
+        assert!(control.is_paused());
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.progress, PruneProgress::Finished);
+        assert!(output.segments.is_empty());
+        assert_eq!(pruner.previous_tip_block_number, None);
+
+        control.resume();
+        let output = pruner.run(10).unwrap();
+        assert!(output.has_pruned_entries());
+        assert_eq!(pruner.previous_tip_block_number, Some(10));
+    }
+
+    #[test]
+    fn checkpoint_stalls() {
+        /// Segment that deletes entries on every run without advancing its checkpoint.
+        #[derive(Debug)]
+        struct StallingSegment;
+
+        impl<Provider> Segment<Provider> for StallingSegment {
+            fn segment(&self) -> PruneSegment {
+                PruneSegment::SenderRecovery
+            }
+
+            fn mode(&self) -> Option<PruneMode> {
+                Some(PruneMode::Full)
+            }
+
+            fn purpose(&self) -> PrunePurpose {
+                PrunePurpose::User
+            }
+
+            fn prune(&self, _: &Provider, _: PruneInput) -> Result<SegmentOutput, PrunerError> {
+                Ok(SegmentOutput {
+                    progress: PruneProgress::Finished,
+                    pruned: 1,
+                    pruned_blocks: None,
+                    checkpoint: Some(SegmentOutputCheckpoint {
+                        block_number: Some(5),
+                        tx_number: None,
+                    }),
+                })
+            }
+        }
+
+        let provider_factory = create_test_provider_factory();
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            provider_factory,
+            vec![Box::new(StallingSegment)],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // The first checkpoint is an advancement
+        assert!(pruner.run(10).unwrap().committed);
+        assert!(pruner.checkpoint_stalls().is_empty());
+
+        // Entries are pruned again with the same checkpoint
+        assert!(pruner.run(10).unwrap().committed);
+        assert_eq!(pruner.checkpoint_stalls(), BTreeMap::from([(PruneSegment::SenderRecovery, 1)]));
+    }
+
+    #[test]
+    fn catchup_weight() {
+        /// Segment pruning with the given catch-up weight.
+        #[derive(Debug)]
+        struct Weighted<S>(S, f64);
+
+        impl<Provider, S: Segment<Provider>> Segment<Provider> for Weighted<S> {
+            fn segment(&self) -> PruneSegment {
+                self.0.segment()
+            }
+
+            fn mode(&self) -> Option<PruneMode> {
+                self.0.mode()
+            }
+
+            fn purpose(&self) -> PrunePurpose {
+                self.0.purpose()
+            }
+
+            fn catchup_weight(&self) -> f64 {
+                self.1
+            }
+
+            fn prune(
+                &self,
+                provider: &Provider,
+                input: PruneInput,
+            ) -> Result<SegmentOutput, PrunerError> {
+                self.0.prune(provider, input)
+            }
+        }
+
+        let db = test_db_with_transaction_senders(10);
+        db.insert_tx_hash_numbers(
+            db.table::<tables::Transactions>()
+                .unwrap()
+                .into_iter()
+                .map(|(tx_number, transaction)| (transaction.hash(), tx_number)),
+        )
+        .unwrap();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![
+                Box::new(Weighted(SenderRecovery::new(PruneMode::Full), 0.5)),
+                Box::new(Weighted(TransactionLookup::new(PruneMode::Full), 0.5)),
+            ],
+            0,
+            10,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // Every segment is limited to half of the entries left to delete in the run
+        let output = pruner.run(10).unwrap();
+        assert_matches!(
+            output.segments.as_slice(),
+            [
+                (PruneSegment::SenderRecovery, sender_recovery),
+                (PruneSegment::TransactionLookup, transaction_lookup),
+            ] if sender_recovery.pruned == 5 && transaction_lookup.pruned == 2
+        );
+    }
+
+    #[test]
+    fn weighted_share() {
+        assert_eq!(super::weighted_share(10, 0.5), 5);
+        assert_eq!(super::weighted_share(10, 1.0), 10);
+        // Weights outside of `0.0..=1.0` are clamped
+        assert_eq!(super::weighted_share(10, 4.0), 10);
+        assert_eq!(super::weighted_share(10, f64::INFINITY), 10);
+        assert_eq!(super::weighted_share(10, -1.0), 0);
+        assert_eq!(super::weighted_share(10, f64::NAN), 0);
+    }
+
+    #[test]
+    fn prune_range() {
+        /// Segment that deletes everything up to the target block with a range delete.
+        #[derive(Debug)]
+        struct RangeSegment;
+
+        impl<Provider> Segment<Provider> for RangeSegment {
+            fn segment(&self) -> PruneSegment {
+                PruneSegment::SenderRecovery
+            }
+
+            fn mode(&self) -> Option<PruneMode> {
+                Some(PruneMode::Full)
+            }
+
+            fn purpose(&self) -> PrunePurpose {
+                PrunePurpose::User
+            }
+
+            fn prune_range(
+                &self,
+                _: &Provider,
+                input: &PruneInput,
+            ) -> Result<Option<SegmentOutput>, PrunerError> {
+                Ok(Some(SegmentOutput {
+                    progress: PruneProgress::Finished,
+                    pruned: 3,
+                    pruned_blocks: None,
+                    checkpoint: Some(SegmentOutputCheckpoint {
+                        block_number: Some(input.to_block),
+                        tx_number: None,
+                    }),
+                }))
+            }
+
+            fn prune(&self, _: &Provider, _: PruneInput) -> Result<SegmentOutput, PrunerError> {
+                unreachable!("range deletion is preferred")
+            }
+        }
+
+        let provider_factory = create_test_provider_factory();
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            provider_factory,
+            vec![Box::new(RangeSegment)],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        let output = pruner.run(10).unwrap();
+        assert_matches!(
+            output.segments.as_slice(),
+            [(PruneSegment::SenderRecovery, segment)] if segment.pruned == 3
+        );
+        assert_eq!(output.oldest_retained, BTreeMap::from([(PruneSegment::SenderRecovery, 11)]));
+    }
+
+    #[test]
+    fn oldest_retained() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.oldest_retained, BTreeMap::from([(PruneSegment::SenderRecovery, 11)]));
+
+        // Taken from the saved checkpoint when there's nothing new to prune
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.oldest_retained, BTreeMap::from([(PruneSegment::SenderRecovery, 11)]));
+    }
+
+    #[test]
+    fn skip_commit_without_pruned_entries() {
+        let provider_factory = create_test_provider_factory();
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            provider_factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            5,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+        let output = pruner.run(10).unwrap();
+        assert!(!output.has_pruned_entries());
+        assert!(!output.committed);
+        assert_eq!(pruner.previous_tip_block_number, Some(10));
+    }
+
+    #[test]
+    fn commit_checkpoint_without_pruned_entries() {
+        /// Segment advancing its checkpoint over blocks without any entries to prune.
+        #[derive(Debug)]
+        struct EmptyBlocksSegment;
+
+        impl<Provider> Segment<Provider> for EmptyBlocksSegment {
+            fn segment(&self) -> PruneSegment {
+                PruneSegment::SenderRecovery
+            }
+
+            fn mode(&self) -> Option<PruneMode> {
+                Some(PruneMode::Full)
+            }
+
+            fn purpose(&self) -> PrunePurpose {
+                PrunePurpose::User
+            }
+
+            fn prune(&self, _: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+                Ok(SegmentOutput {
+                    progress: PruneProgress::Finished,
+                    pruned: 0,
+                    pruned_blocks: None,
+                    checkpoint: Some(SegmentOutputCheckpoint {
+                        block_number: Some(input.to_block),
+                        tx_number: None,
+                    }),
+                })
+            }
+        }
+
+        let provider_factory = create_test_provider_factory();
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            provider_factory.clone(),
+            vec![Box::new(EmptyBlocksSegment)],
+            0,
+            100,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // The advanced checkpoint is committed, even though nothing was pruned
+        let output = pruner.run(10).unwrap();
+        assert!(!output.has_pruned_entries());
+        assert!(output.checkpoints_updated);
+        assert!(output.committed);
+        assert_eq!(
+            provider_factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::SenderRecovery)
+                .unwrap()
+                .and_then(|checkpoint| checkpoint.block_number),
+            Some(10)
+        );
+
+        // The same checkpoint isn't committed again
+        let output = pruner.run(10).unwrap();
+        assert!(!output.checkpoints_updated);
+        assert!(!output.committed);
+    }
+
+    #[test]
+    fn size_target() {
+        let db = test_db_with_transaction_senders(10);
+
+        // Every transaction sender takes 100 bytes, and the database fits 10 of them
+        let factory = db.factory.clone();
+        let table_sizes: TableSizes = Arc::new(move || {
+            let senders = factory.provider()?.count_entries::<tables::TransactionSenders>()?;
+            Ok(BTreeMap::from([(
+                tables::TransactionSenders::NAME.to_string(),
+                senders as u64 * 100,
+            )]))
+        });
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            4,
+            None,
+            finished_exex_height_rx,
+        )
+        .with_size_target(1000, table_sizes);
+
+        // 20 transaction senders of 10 blocks are stored, and every run prunes 4 of them until at
+        // most 10 are left
+        let output = pruner.run_to_completion(10).unwrap();
+        assert!(output.segments.is_empty());
+        assert_eq!(db.table::<tables::TransactionSenders>().unwrap().len(), 8);
+        assert_eq!(
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::SenderRecovery)
+                .unwrap()
+                .and_then(|checkpoint| checkpoint.block_number),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn rate_limit() {
+        let db = test_db_with_transaction_senders(10);
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let mut pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            0,
+            100,
+            Some(Duration::from_millis(100)),
+            finished_exex_height_rx,
+        )
+        .with_rate_limit(NonZeroUsize::new(10).unwrap());
+
+        // The first batch is allowed immediately, and is smaller than the delete limit
+        let output = pruner.run(10).unwrap();
+        assert_matches!(
+            output.segments.as_slice(),
+            [(PruneSegment::SenderRecovery, segment)] if segment.pruned == 10
+        );
+
+        // The rate limiter would need to wait longer than the run timeout for the next batch
+        let output = pruner.run(10).unwrap();
+        assert_eq!(output.progress, PruneProgress::HasMoreData(PruneInterruptReason::Timeout));
+        assert!(output.segments.is_empty());
     }
 }
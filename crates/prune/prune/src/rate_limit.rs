@@ -0,0 +1,96 @@
+use std::{
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+/// Token bucket limiting the sustained number of deleted entries (rows in the database) per second.
+///
+/// The bucket holds at most one second worth of tokens, so deletions can burst up to the
+/// configured rate after a quiet period.
+#[derive(Debug)]
+pub(crate) struct DeletionRateLimiter {
+    /// Number of tokens refilled per second, and the capacity of the bucket.
+    deletions_per_second: NonZeroUsize,
+    /// Currently available tokens.
+    tokens: f64,
+    /// Time of the last refill.
+    last_refill: Instant,
+}
+
+impl DeletionRateLimiter {
+    /// Creates a new rate limiter with a full bucket.
+    pub(crate) fn new(deletions_per_second: NonZeroUsize) -> Self {
+        Self {
+            deletions_per_second,
+            tokens: deletions_per_second.get() as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Returns the size of the next batch, which is the capacity of the bucket, limited by the
+    /// `entries_left` to delete.
+    pub(crate) fn batch_size(&self, entries_left: Option<usize>) -> usize {
+        let capacity = self.deletions_per_second.get();
+        entries_left.map_or(capacity, |left| left.min(capacity))
+    }
+
+    /// Returns the time to wait until `tokens` tokens are available.
+    pub(crate) fn wait_time(&mut self, tokens: usize) -> Duration {
+        self.refill(Instant::now());
+        let missing = tokens as f64 - self.tokens;
+        if missing <= 0.0 {
+            return Duration::ZERO
+        }
+        Duration::from_secs_f64(missing / self.deletions_per_second.get() as f64)
+    }
+
+    /// Takes `tokens` tokens from the bucket, possibly leaving it in debt which is paid off by the
+    /// future refills.
+    pub(crate) fn acquire(&mut self, tokens: usize) {
+        self.refill(Instant::now());
+        self.tokens -= tokens as f64;
+    }
+
+    /// Returns `tokens` unused tokens to the bucket.
+    pub(crate) fn release(&mut self, tokens: usize) {
+        self.tokens = (self.tokens + tokens as f64).min(self.deletions_per_second.get() as f64);
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let capacity = self.deletions_per_second.get() as f64;
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * capacity).min(capacity);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeletionRateLimiter;
+    use std::{num::NonZeroUsize, time::Duration};
+
+    #[test]
+    fn token_bucket() {
+        let mut rate_limiter = DeletionRateLimiter::new(NonZeroUsize::new(100).unwrap());
+        assert_eq!(rate_limiter.batch_size(None), 100);
+        assert_eq!(rate_limiter.batch_size(Some(30)), 30);
+        assert_eq!(rate_limiter.batch_size(Some(300)), 100);
+
+        // The bucket is full initially
+        assert_eq!(rate_limiter.wait_time(100), Duration::ZERO);
+        rate_limiter.acquire(100);
+
+        // Refilling the whole bucket takes about a second
+        let wait_time = rate_limiter.wait_time(100);
+        assert!(wait_time > Duration::from_millis(900) && wait_time <= Duration::from_secs(1));
+
+        // Unused tokens are returned
+        rate_limiter.release(50);
+        let wait_time = rate_limiter.wait_time(100);
+        assert!(wait_time > Duration::from_millis(400) && wait_time <= Duration::from_millis(500));
+
+        // Returned tokens never exceed the capacity
+        rate_limiter.release(1000);
+        assert_eq!(rate_limiter.wait_time(100), Duration::ZERO);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{segments::Segment, PrunerError};
+use std::fmt::Debug;
+
+/// Visitor of the segments in [`Segments`].
+///
+/// Called with the concrete type of each segment, so segments stored in a tuple are pruned with
+/// static dispatch.
+pub trait SegmentVisitor<Provider> {
+    /// Visits the segment. Returns `false` if the remaining segments shouldn't be visited.
+    fn visit<S: Segment<Provider> + ?Sized>(&mut self, segment: &S) -> Result<bool, PrunerError>;
+}
+
+/// Ordered collection of segments that are pruned by [`Pruner`](crate::Pruner).
+///
+/// Implemented for a [`Vec`] of boxed segments, and for tuples of up to eight concrete segments
+/// that avoid the dynamic dispatch and allocations.
+pub trait Segments<Provider>: Debug + Send + Sync {
+    /// Returns the number of segments.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no segments.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Visits the segments in order, until the visitor returns `false`.
+    fn visit<V: SegmentVisitor<Provider>>(&self, visitor: &mut V) -> Result<(), PrunerError> {
+        self.visit_from(0, visitor)
+    }
+
+    /// Visits the segments in order starting at the segment with index `start`, wrapping around
+    /// to the first segment after the last one, until the visitor returns `false`.
+    fn visit_from<V: SegmentVisitor<Provider>>(
+        &self,
+        start: usize,
+        visitor: &mut V,
+    ) -> Result<(), PrunerError>;
+}
+
+impl<Provider> Segments<Provider> for Vec<Box<dyn Segment<Provider>>> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn visit_from<V: SegmentVisitor<Provider>>(
+        &self,
+        start: usize,
+        visitor: &mut V,
+    ) -> Result<(), PrunerError> {
+        let start = if self.is_empty() { 0 } else { start % Vec::len(self) };
+        for segment in self[start..].iter().chain(&self[..start]) {
+            if !visitor.visit(segment.as_ref())? {
+                break
+            }
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_segments_for_tuple {
+    ($len:expr; $($segment:ident: $index:tt),+) => {
+        impl<Provider, $($segment),+> Segments<Provider> for ($($segment,)+)
+        where
+            $($segment: Segment<Provider>),+
+        {
+            fn len(&self) -> usize {
+                $len
+            }
+
+            fn visit_from<V: SegmentVisitor<Provider>>(
+                &self,
+                start: usize,
+                visitor: &mut V,
+            ) -> Result<(), PrunerError> {
+                for offset in 0..$len {
+                    let proceed = match (start % $len + offset) % $len {
+                        $($index => visitor.visit(&self.$index)?,)+
+                        _ => unreachable!("index is less than the number of segments"),
+                    };
+                    if !proceed {
+                        return Ok(())
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_segments_for_tuple!(1; A: 0);
+impl_segments_for_tuple!(2; A: 0, B: 1);
+impl_segments_for_tuple!(3; A: 0, B: 1, C: 2);
+impl_segments_for_tuple!(4; A: 0, B: 1, C: 2, D: 3);
+impl_segments_for_tuple!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_segments_for_tuple!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_segments_for_tuple!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_segments_for_tuple!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
@@ -1,20 +1,30 @@
+mod dispatch;
 mod receipts;
+mod sender_recovery;
 mod set;
+mod size_targeted;
 mod static_file;
 mod user;
 
 use crate::PrunerError;
 use alloy_primitives::{BlockNumber, TxNumber};
+pub use dispatch::{SegmentVisitor, Segments};
 use reth_provider::{errors::provider::ProviderResult, BlockReader, PruneCheckpointWriter};
 use reth_prune_types::{
-    PruneCheckpoint, PruneLimiter, PruneMode, PrunePurpose, PruneSegment, SegmentOutput,
+    PruneCheckpoint, PruneEveryNth, PruneLimiter, PruneMode, PrunePurpose, PruneSegment,
+    SegmentOutput,
 };
 pub use set::SegmentSet;
+pub(crate) use size_targeted::DatabaseSizeTarget;
+pub use size_targeted::{SizeTargetedSegment, TableSizes};
 pub use static_file::{
     Headers as StaticFileHeaders, Receipts as StaticFileReceipts,
-    Transactions as StaticFileTransactions,
+    TransactionSenders as StaticFileTransactionSenders, Transactions as StaticFileTransactions,
+};
+use std::{
+    fmt::Debug,
+    ops::{Range, RangeInclusive},
 };
-use std::{fmt::Debug, ops::RangeInclusive};
 use tracing::error;
 pub use user::{
     AccountHistory, Receipts as UserReceipts, ReceiptsByLogs, SenderRecovery, StorageHistory,
@@ -38,9 +48,35 @@ pub trait Segment<Provider>: Debug + Send + Sync {
     /// Purpose of the segment.
     fn purpose(&self) -> PrunePurpose;
 
+    /// Weight of the segment's share of the entries left to delete in a
+    /// [`Pruner`](crate::Pruner) run.
+    ///
+    /// The weight is clamped to `0.0..=1.0`, and the segment may delete at most `weight` times the
+    /// entries left to delete when it's pruned, i.e. the delete limit minus the entries already
+    /// deleted by the segments pruned before it in the run. Segments whose data accumulates slower
+    /// than others can declare a weight below `1.0` to leave more of the budget to the segments
+    /// pruned after them.
+    fn catchup_weight(&self) -> f64 {
+        1.0
+    }
+
     /// Prune data for [`Self::segment`] using the provided input.
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError>;
 
+    /// Prune data for [`Self::segment`] using the provided input by deleting a contiguous key range
+    /// at once, if the segment and the backing store support it.
+    ///
+    /// Preferred over [`Self::prune`] by the [`Pruner`](crate::Pruner). Returns `None` if range
+    /// deletion isn't applicable, in which case [`Self::prune`] is called with the same input.
+    fn prune_range(
+        &self,
+        provider: &Provider,
+        input: &PruneInput,
+    ) -> Result<Option<SegmentOutput>, PrunerError> {
+        let _ = (provider, input);
+        Ok(None)
+    }
+
     /// Save checkpoint for [`Self::segment`] to the database.
     fn save_checkpoint(
         &self,
@@ -63,6 +99,9 @@ pub struct PruneInput {
     pub(crate) to_block: BlockNumber,
     /// Limits pruning of a segment.
     pub(crate) limiter: PruneLimiter,
+    /// Whether the entries to prune are only counted, without modifying the database, see
+    /// [`Pruner::dry_run`](crate::Pruner::dry_run).
+    pub(crate) dry_run: bool,
 }
 
 impl PruneInput {
@@ -112,6 +151,31 @@ impl PruneInput {
         Ok(Some(range))
     }
 
+    /// Get tx number ranges of the blocks within the next block range to prune that must be
+    /// retained according to the [`PruneEveryNth`] configuration.
+    pub(crate) fn get_retained_tx_num_ranges<Provider: BlockReader>(
+        &self,
+        provider: &Provider,
+        every_nth: PruneEveryNth,
+    ) -> ProviderResult<Vec<Range<TxNumber>>> {
+        let Some(block_range) = self.get_next_block_range() else { return Ok(Vec::new()) };
+        if every_nth.keep_every == 0 {
+            return Ok(Vec::new())
+        }
+
+        let first_retained_block = block_range.start().next_multiple_of(every_nth.keep_every);
+        let mut ranges = Vec::new();
+        for block in (first_retained_block..=*block_range.end())
+            .step_by(every_nth.keep_every.try_into().unwrap_or(usize::MAX))
+        {
+            if let Some(body) = provider.block_body_indices(block)? {
+                ranges.push(body.tx_num_range());
+            }
+        }
+
+        Ok(ranges)
+    }
+
     /// Get next inclusive block range to prune according to the checkpoint, `to_block` block
     /// number and `limit`.
     ///
@@ -160,6 +224,7 @@ mod tests {
             previous_checkpoint: None,
             to_block: 10,
             limiter: PruneLimiter::default(),
+            dry_run: false,
         };
 
         // Default provider with no block corresponding to block 10
@@ -176,6 +241,7 @@ mod tests {
             previous_checkpoint: None,
             to_block: 10,
             limiter: PruneLimiter::default(),
+            dry_run: false,
         };
 
         let mut rng = generators::rng();
@@ -214,6 +280,7 @@ mod tests {
             previous_checkpoint: None,
             to_block: 10,
             limiter: PruneLimiter::default(),
+            dry_run: false,
         };
 
         let mut rng = generators::rng();
@@ -261,6 +328,7 @@ mod tests {
             }),
             to_block: 10,
             limiter: PruneLimiter::default(),
+            dry_run: false,
         };
 
         let mut rng = generators::rng();
@@ -339,6 +407,7 @@ mod tests {
             }),
             to_block: 10,
             limiter: PruneLimiter::default(),
+            dry_run: false,
         };
 
         // We expect an empty range since the previous checkpoint is the last tx number
@@ -6,19 +6,26 @@
 //!   node after static file producer has finished
 
 use crate::{db_ext::DbTxPruneExt, segments::PruneInput, PrunerError};
+use alloy_primitives::TxNumber;
 use reth_db::{tables, transaction::DbTxMut};
 use reth_provider::{
     errors::provider::ProviderResult, BlockReader, DBProvider, PruneCheckpointWriter,
     TransactionsProvider,
 };
 use reth_prune_types::{
-    PruneCheckpoint, PruneProgress, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
+    PruneCheckpoint, PruneEveryNth, PruneLimiter, PruneProgress, PruneSegment, SegmentOutput,
+    SegmentOutputCheckpoint,
 };
 use tracing::trace;
 
+/// Prunes receipts according to the provided input.
+///
+/// If `every_nth` is set, receipts of the retained blocks are skipped, see
+/// [`PruneEveryNth::is_retained`].
 pub(crate) fn prune<Provider>(
     provider: &Provider,
     input: PruneInput,
+    every_nth: Option<PruneEveryNth>,
 ) -> Result<SegmentOutput, PrunerError>
 where
     Provider: DBProvider<Tx: DbTxMut> + TransactionsProvider + BlockReader,
@@ -32,17 +39,75 @@ where
     };
     let tx_range_end = *tx_range.end();
 
+    let retained_tx_ranges = match every_nth {
+        Some(every_nth) => input.get_retained_tx_num_ranges(provider, every_nth)?,
+        None => Vec::new(),
+    };
+
     let mut limiter = input.limiter;
 
     let mut last_pruned_transaction = tx_range_end;
     let (pruned, done) = provider.tx_ref().prune_table_with_range::<tables::Receipts>(
         tx_range,
         &mut limiter,
-        |_| false,
+        input.dry_run,
+        |(tx_num, _)| retained_tx_ranges.iter().any(|range| range.contains(tx_num)),
         |row| last_pruned_transaction = row.0,
     )?;
+    if done && !retained_tx_ranges.is_empty() {
+        // Receipts of the retained blocks at the end of the range were skipped, but the whole
+        // range has been processed.
+        last_pruned_transaction = tx_range_end;
+    }
     trace!(target: "pruner", %pruned, %done, "Pruned receipts");
 
+    segment_output(provider, pruned, done, last_pruned_transaction, &limiter)
+}
+
+/// Prunes receipts according to the provided input, deleting them as a contiguous range of
+/// transaction numbers without decoding them.
+///
+/// Unlike [`prune`], receipts can't be retained within the range.
+pub(crate) fn prune_range<Provider>(
+    provider: &Provider,
+    input: &PruneInput,
+) -> Result<SegmentOutput, PrunerError>
+where
+    Provider: DBProvider<Tx: DbTxMut> + TransactionsProvider + BlockReader,
+{
+    let tx_range = match input.get_next_tx_num_range(provider)? {
+        Some(range) => range,
+        None => {
+            trace!(target: "pruner", "No receipts to prune");
+            return Ok(SegmentOutput::done())
+        }
+    };
+    let tx_range_end = *tx_range.end();
+
+    let mut limiter = input.limiter.clone();
+
+    let (pruned, done, last_pruned_transaction) = provider
+        .tx_ref()
+        .prune_table_with_raw_range::<tables::Receipts>(tx_range, &mut limiter, input.dry_run)?;
+    trace!(target: "pruner", %pruned, %done, "Pruned receipts range");
+
+    segment_output(
+        provider,
+        pruned,
+        done,
+        last_pruned_transaction.unwrap_or(tx_range_end),
+        &limiter,
+    )
+}
+
+/// Returns the output of pruning `pruned` receipts up to the `last_pruned_transaction`.
+fn segment_output<Provider: BlockReader>(
+    provider: &Provider,
+    pruned: usize,
+    done: bool,
+    last_pruned_transaction: TxNumber,
+    limiter: &PruneLimiter,
+) -> Result<SegmentOutput, PrunerError> {
     let last_pruned_block = provider
         .transaction_block(last_pruned_transaction)?
         .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
@@ -50,11 +115,12 @@ where
         // so we could finish pruning its receipts on the next run.
         .checked_sub(if done { 0 } else { 1 });
 
-    let progress = PruneProgress::new(done, &limiter);
+    let progress = PruneProgress::new(done, limiter);
 
     Ok(SegmentOutput {
         progress,
         pruned,
+        pruned_blocks: None,
         checkpoint: Some(SegmentOutputCheckpoint {
             block_number: last_pruned_block,
             tx_number: Some(last_pruned_transaction),
@@ -87,7 +153,8 @@ mod tests {
     use reth_db::tables;
     use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
     use reth_prune_types::{
-        PruneCheckpoint, PruneInterruptReason, PruneLimiter, PruneMode, PruneProgress, PruneSegment,
+        PruneCheckpoint, PruneEveryNth, PruneInterruptReason, PruneLimiter, PruneMode,
+        PruneProgress, PruneSegment,
     };
     use reth_stages::test_utils::{StorageKind, TestStageDB};
     use reth_testing_utils::generators::{
@@ -139,6 +206,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
 
             let next_tx_number_to_prune = db
@@ -163,12 +231,12 @@ mod tests {
                 .sub(1);
 
             let provider = db.factory.database_provider_rw().unwrap();
-            let result = super::prune(&provider, input).unwrap();
+            let result = super::prune(&provider, input, None).unwrap();
             limiter.increment_deleted_entries_count_by(result.pruned);
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
 
@@ -219,4 +287,133 @@ mod tests {
         test_prune(6, (PruneProgress::Finished, 2));
         test_prune(10, (PruneProgress::Finished, 8));
     }
+
+    #[test]
+    fn prune_every_nth() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut receipts = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                receipts
+                    .push((receipts.len() as u64, random_receipt(&mut rng, transaction, Some(0))));
+            }
+        }
+        db.insert_receipts(receipts).expect("insert receipts");
+
+        let to_block = 6;
+        let every_nth = PruneEveryNth { keep_every: 3, before: to_block + 1 };
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block,
+            limiter: PruneLimiter::default(),
+            dry_run: false,
+        };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = super::prune(&provider, input, Some(every_nth)).unwrap();
+        provider.commit().expect("commit");
+
+        let last_tx_number = blocks
+            .iter()
+            .take(to_block as usize)
+            .map(|block| block.body.transactions.len() as u64)
+            .sum::<u64>() -
+            1;
+        assert_matches!(
+            result,
+            SegmentOutput {
+                progress: PruneProgress::Finished,
+                checkpoint: Some(checkpoint),
+                ..
+            } if checkpoint.block_number == Some(to_block) &&
+                checkpoint.tx_number == Some(last_tx_number)
+        );
+
+        // Receipts of the blocks 3 and 6 are retained, as well as all receipts after block 6
+        let mut tx_number = 0;
+        let mut expected_receipts = Vec::new();
+        for block in &blocks {
+            for _ in &block.body.transactions {
+                if block.number > to_block || every_nth.is_retained(block.number) {
+                    expected_receipts.push(tx_number);
+                }
+                tx_number += 1;
+            }
+        }
+        assert_eq!(
+            db.table::<tables::Receipts>()
+                .unwrap()
+                .into_iter()
+                .map(|(tx_number, _)| tx_number)
+                .collect::<Vec<_>>(),
+            expected_receipts
+        );
+    }
+
+    #[test]
+    fn prune_range() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut receipts = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                receipts
+                    .push((receipts.len() as u64, random_receipt(&mut rng, transaction, Some(0))));
+            }
+        }
+        let receipts_len = receipts.len();
+        db.insert_receipts(receipts).expect("insert receipts");
+
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block: 6,
+            limiter: PruneLimiter::default().set_deleted_entries_limit(10),
+            dry_run: false,
+        };
+
+        // The range deletion reports the same output as the row-by-row deletion
+        let provider = db.factory.database_provider_rw().unwrap();
+        let expected =
+            super::prune(&provider, PruneInput { dry_run: true, ..input.clone() }, None).unwrap();
+        let result = super::prune_range(&provider, &input).unwrap();
+        provider.commit().expect("commit");
+
+        assert_eq!(result, expected);
+        assert_matches!(
+            result,
+            SegmentOutput {
+                progress: PruneProgress::HasMoreData(
+                    PruneInterruptReason::DeletedEntriesLimitReached
+                ),
+                pruned: 10,
+                checkpoint: Some(checkpoint),
+                ..
+            } if checkpoint.tx_number == Some(9)
+        );
+        assert_eq!(
+            db.table::<tables::Receipts>()
+                .unwrap()
+                .into_iter()
+                .map(|(tx_number, _)| tx_number)
+                .collect::<Vec<_>>(),
+            (10..receipts_len as TxNumber).collect::<Vec<_>>()
+        );
+    }
 }
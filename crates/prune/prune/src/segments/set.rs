@@ -4,12 +4,14 @@ use crate::segments::{
 };
 use reth_db::transaction::DbTxMut;
 use reth_provider::{
-    providers::StaticFileProvider, BlockReader, DBProvider, PruneCheckpointWriter,
-    StaticFileProviderFactory, TransactionsProvider,
+    providers::StaticFileProvider, BlockReader, DBProvider, PruneCheckpointReader,
+    PruneCheckpointWriter, StaticFileProviderFactory, TransactionsProvider,
 };
 use reth_prune_types::PruneModes;
 
-use super::{StaticFileHeaders, StaticFileReceipts, StaticFileTransactions};
+use super::{
+    StaticFileHeaders, StaticFileReceipts, StaticFileTransactionSenders, StaticFileTransactions,
+};
 
 /// Collection of [`Segment`]. Thread-safe, allocated on the heap.
 #[derive(Debug)]
@@ -48,6 +50,7 @@ where
     Provider: StaticFileProviderFactory
         + DBProvider<Tx: DbTxMut>
         + TransactionsProvider
+        + PruneCheckpointReader
         + PruneCheckpointWriter
         + BlockReader,
 {
@@ -71,6 +74,8 @@ where
             .segment(StaticFileHeaders::new(static_file_provider.clone()))
             // Static file transactions
             .segment(StaticFileTransactions::new(static_file_provider.clone()))
+            // Static file transaction senders
+            .segment(StaticFileTransactionSenders::new(static_file_provider.clone()))
             // Static file receipts
             .segment(StaticFileReceipts::new(static_file_provider))
             // Account history
@@ -0,0 +1,248 @@
+use crate::{
+    segments::{PruneInput, Segment},
+    PrunerError,
+};
+use alloy_primitives::BlockNumber;
+use reth_provider::{errors::provider::ProviderResult, PruneCheckpointWriter};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment, SegmentOutput};
+use std::{collections::BTreeMap, fmt, sync::Arc};
+use tracing::{instrument, trace};
+
+/// Query returning the size in bytes of every database table, keyed by table name.
+///
+/// Usually backed by [`ProviderFactory::table_sizes`](reth_provider::ProviderFactory::table_sizes).
+pub type TableSizes = Arc<dyn Fn() -> ProviderResult<BTreeMap<String, u64>> + Send + Sync>;
+
+/// Wraps a [`Segment`] to retain as many of the latest blocks as fit into a size target of the
+/// segment's table, instead of a fixed number of blocks.
+///
+/// On every run, the number of oldest blocks to prune is estimated from the current table size and
+/// the average size of a block in the table, so the target follows the growth of the table. The
+/// prune mode of the wrapped segment is ignored, but the minimum number of blocks required by the
+/// segment is always kept.
+pub struct SizeTargetedSegment<S> {
+    segment: S,
+    /// Name of the table which size is targeted.
+    table: &'static str,
+    /// Maximum size of the table in bytes.
+    target_size: u64,
+    table_sizes: TableSizes,
+}
+
+impl<S> SizeTargetedSegment<S> {
+    /// Creates a new segment that prunes `segment` until the size of `table`, as reported by
+    /// `table_sizes`, fits into `target_size` bytes.
+    pub fn new(segment: S, table: &'static str, target_size: u64, table_sizes: TableSizes) -> Self {
+        Self { segment, table, target_size, table_sizes }
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for SizeTargetedSegment<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SizeTargetedSegment")
+            .field("segment", &self.segment)
+            .field("table", &self.table)
+            .field("target_size", &self.target_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, Provider> Segment<Provider> for SizeTargetedSegment<S>
+where
+    S: Segment<Provider>,
+{
+    fn segment(&self) -> PruneSegment {
+        self.segment.segment()
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        // Allow pruning everything but the minimum number of blocks, the actual target is
+        // calculated from the table size in `prune`.
+        Some(PruneMode::Distance(self.segment.segment().min_blocks(self.segment.purpose())))
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        self.segment.purpose()
+    }
+
+    fn catchup_weight(&self) -> f64 {
+        self.segment.catchup_weight()
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(
+        &self,
+        provider: &Provider,
+        mut input: PruneInput,
+    ) -> Result<SegmentOutput, PrunerError> {
+        let table_size = (self.table_sizes)()?.get(self.table).copied().unwrap_or_default();
+        if table_size <= self.target_size {
+            trace!(target: "pruner", table = %self.table, %table_size, target_size = %self.target_size, "Table is within the size target");
+            return Ok(SegmentOutput::done())
+        }
+
+        let first_block = input
+            .previous_checkpoint
+            .and_then(|checkpoint| checkpoint.block_number)
+            .map_or(0, |block_number| block_number + 1);
+        let tip = input.to_block + self.segment.segment().min_blocks(self.segment.purpose());
+        let Some(to_block) =
+            blocks_to_prune(table_size, self.target_size, first_block, tip).map(|to_block| {
+                // Never prune beyond the target allowed by the segment
+                to_block.min(input.to_block)
+            })
+        else {
+            return Ok(SegmentOutput::done())
+        };
+        trace!(target: "pruner", table = %self.table, %table_size, target_size = %self.target_size, %to_block, "Pruning table to the size target");
+
+        input.to_block = to_block;
+        self.segment.prune(provider, input)
+    }
+
+    fn save_checkpoint(
+        &self,
+        provider: &Provider,
+        checkpoint: PruneCheckpoint,
+    ) -> ProviderResult<()>
+    where
+        Provider: PruneCheckpointWriter,
+    {
+        self.segment.save_checkpoint(provider, checkpoint)
+    }
+}
+
+/// Maximum size of all database tables, see
+/// [`Pruner::with_size_target`](crate::Pruner::with_size_target).
+pub(crate) struct DatabaseSizeTarget {
+    /// Maximum size of all tables in bytes.
+    pub(crate) target_size: u64,
+    pub(crate) table_sizes: TableSizes,
+}
+
+impl DatabaseSizeTarget {
+    /// Returns `true` if the total size of all tables is within the target.
+    pub(crate) fn is_reached(&self) -> ProviderResult<bool> {
+        let size = (self.table_sizes)()?.values().sum::<u64>();
+        trace!(target: "pruner", %size, target_size = %self.target_size, "Checked database size");
+        Ok(size <= self.target_size)
+    }
+}
+
+impl fmt::Debug for DatabaseSizeTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DatabaseSizeTarget")
+            .field("target_size", &self.target_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the highest block to prune, so that the table of `table_size` bytes storing the blocks
+/// `first_block..=tip` shrinks to `target_size` bytes, assuming all blocks are of the same size.
+///
+/// Returns `None` if there's nothing to prune.
+fn blocks_to_prune(
+    table_size: u64,
+    target_size: u64,
+    first_block: BlockNumber,
+    tip: BlockNumber,
+) -> Option<BlockNumber> {
+    let stored_blocks = (tip + 1).checked_sub(first_block).filter(|blocks| *blocks > 0)?;
+    let excess_size = table_size.saturating_sub(target_size) as u128;
+    let blocks = (excess_size * stored_blocks as u128).div_ceil(table_size as u128) as u64;
+    (blocks > 0).then(|| first_block + blocks - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{PruneInput, Segment, SenderRecovery, SizeTargetedSegment};
+    use alloy_primitives::B256;
+    use assert_matches::assert_matches;
+    use reth_db::{table::Table, tables};
+    use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
+    use reth_prune_types::{PruneLimiter, PruneMode, PruneProgress, PruneSegment, SegmentOutput};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::{collections::BTreeMap, sync::Arc};
+
+    #[test]
+    fn blocks_to_prune() {
+        // Half of the table needs to be pruned
+        assert_eq!(super::blocks_to_prune(1000, 500, 0, 9), Some(4));
+        // Round up to stay within the target
+        assert_eq!(super::blocks_to_prune(1000, 550, 0, 9), Some(4));
+        assert_eq!(super::blocks_to_prune(1000, 600, 5, 14), Some(8));
+        // Table is within the target
+        assert_eq!(super::blocks_to_prune(500, 500, 5, 14), None);
+        // Table doesn't store any blocks
+        assert_eq!(super::blocks_to_prune(1000, 500, 15, 14), None);
+    }
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut transaction_senders = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                transaction_senders.push((
+                    transaction_senders.len() as u64,
+                    transaction.recover_signer().expect("recover signer"),
+                ));
+            }
+        }
+        db.insert_transaction_senders(transaction_senders).expect("insert transaction senders");
+
+        let table_size = 1000;
+        let segment = |target_size| {
+            SizeTargetedSegment::new(
+                SenderRecovery::new(PruneMode::Full),
+                tables::TransactionSenders::NAME,
+                target_size,
+                Arc::new(move || {
+                    Ok(BTreeMap::from([(tables::TransactionSenders::NAME.to_string(), table_size)]))
+                }),
+            )
+        };
+        let input = |db: &TestStageDB| PruneInput {
+            previous_checkpoint: db
+                .factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::SenderRecovery)
+                .unwrap(),
+            to_block: 10,
+            limiter: PruneLimiter::default(),
+            dry_run: false,
+        };
+
+        // Table is within the size target
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment(table_size).prune(&provider, input(&db)).unwrap();
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, pruned: 0, checkpoint: None, .. }
+        );
+
+        // 11 blocks are stored including genesis, so 6 of them need to be pruned to fit into
+        // the target
+        let result = segment(table_size / 2).prune(&provider, input(&db)).unwrap();
+        let last_pruned_tx =
+            blocks.iter().take(5).map(|block| block.body.transactions.len()).sum::<usize>() - 1;
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, pruned, checkpoint: Some(checkpoint), .. }
+                if pruned == last_pruned_tx + 1 &&
+                    checkpoint.block_number == Some(5) &&
+                    checkpoint.tx_number == Some(last_pruned_tx as u64)
+        );
+    }
+}
@@ -78,6 +78,7 @@ impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Prov
         let tables_iter = HeaderTablesIter::new(
             provider,
             &mut limiter,
+            input.dry_run,
             headers_cursor.walk_range(range.clone())?,
             header_tds_cursor.walk_range(range.clone())?,
             canonical_headers_cursor.walk_range(range)?,
@@ -85,10 +86,12 @@ impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Prov
 
         let mut last_pruned_block: Option<u64> = None;
         let mut pruned = 0;
+        let mut pruned_blocks = 0;
         for res in tables_iter {
             let HeaderTablesIterItem { pruned_block, entries_pruned } = res?;
             last_pruned_block = Some(pruned_block);
             pruned += entries_pruned;
+            pruned_blocks += 1;
         }
 
         let done = last_pruned_block == Some(block_range_end);
@@ -97,6 +100,7 @@ impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Prov
         Ok(SegmentOutput {
             progress,
             pruned,
+            pruned_blocks: Some(pruned_blocks),
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: last_pruned_block,
                 tx_number: None,
@@ -114,6 +118,7 @@ where
 {
     provider: &'a Provider,
     limiter: &'a mut PruneLimiter,
+    dry_run: bool,
     headers_walker: Walker<'a, Provider, tables::Headers>,
     header_tds_walker: Walker<'a, Provider, tables::HeaderTerminalDifficulties>,
     canonical_headers_walker: Walker<'a, Provider, tables::CanonicalHeaders>,
@@ -131,11 +136,19 @@ where
     fn new(
         provider: &'a Provider,
         limiter: &'a mut PruneLimiter,
+        dry_run: bool,
         headers_walker: Walker<'a, Provider, tables::Headers>,
         header_tds_walker: Walker<'a, Provider, tables::HeaderTerminalDifficulties>,
         canonical_headers_walker: Walker<'a, Provider, tables::CanonicalHeaders>,
     ) -> Self {
-        Self { provider, limiter, headers_walker, header_tds_walker, canonical_headers_walker }
+        Self {
+            provider,
+            limiter,
+            dry_run,
+            headers_walker,
+            header_tds_walker,
+            canonical_headers_walker,
+        }
     }
 }
 
@@ -156,6 +169,7 @@ where
         if let Err(err) = self.provider.tx_ref().prune_table_with_range_step(
             &mut self.headers_walker,
             self.limiter,
+            self.dry_run,
             &mut |_| false,
             &mut |row| pruned_block_headers = Some(row.0),
         ) {
@@ -165,6 +179,7 @@ where
         if let Err(err) = self.provider.tx_ref().prune_table_with_range_step(
             &mut self.header_tds_walker,
             self.limiter,
+            self.dry_run,
             &mut |_| false,
             &mut |row| pruned_block_td = Some(row.0),
         ) {
@@ -174,6 +189,7 @@ where
         if let Err(err) = self.provider.tx_ref().prune_table_with_range_step(
             &mut self.canonical_headers_walker,
             self.limiter,
+            self.dry_run,
             &mut |_| false,
             &mut |row| pruned_block_canonical = Some(row.0),
         ) {
@@ -244,6 +260,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
 
             let next_block_number_to_prune = db
@@ -268,9 +285,10 @@ mod tests {
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
+            assert_eq!(result.pruned_blocks, Some((result.pruned / HEADER_TABLES_TO_PRUNE) as u64));
             provider
                 .save_prune_checkpoint(
                     PruneSegment::Headers,
@@ -325,6 +343,7 @@ mod tests {
             to_block: 1,
             // Less than total number of tables for `Headers` segment
             limiter,
+            dry_run: false,
         };
 
         let provider = db.factory.database_provider_rw().unwrap();
@@ -44,7 +44,15 @@ where
     }
 
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        crate::segments::receipts::prune(provider, input)
+        crate::segments::receipts::prune(provider, input, None)
+    }
+
+    fn prune_range(
+        &self,
+        provider: &Provider,
+        input: &PruneInput,
+    ) -> Result<Option<SegmentOutput>, PrunerError> {
+        crate::segments::receipts::prune_range(provider, input).map(Some)
     }
 
     fn save_checkpoint(
@@ -0,0 +1,169 @@
+use crate::{
+    segments::{PruneInput, Segment},
+    PrunerError,
+};
+use reth_db::transaction::DbTxMut;
+use reth_provider::{
+    providers::StaticFileProvider, BlockReader, DBProvider, PruneCheckpointReader,
+    StaticFileProviderFactory, TransactionsProvider,
+};
+use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutput};
+use reth_static_file_types::StaticFileSegment;
+use tracing::trace;
+
+/// Prunes `TransactionSenders` entries of transactions that were already moved to static files and
+/// pruned from the database by [`Transactions`](super::Transactions).
+#[derive(Debug)]
+pub struct TransactionSenders<N> {
+    static_file_provider: StaticFileProvider<N>,
+}
+
+impl<N> TransactionSenders<N> {
+    pub const fn new(static_file_provider: StaticFileProvider<N>) -> Self {
+        Self { static_file_provider }
+    }
+}
+
+impl<Provider> Segment<Provider> for TransactionSenders<Provider::Primitives>
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + TransactionsProvider
+        + BlockReader
+        + PruneCheckpointReader
+        + StaticFileProviderFactory,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::TransactionSenders
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        self.static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::Transactions)
+            .map(PruneMode::before_inclusive)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::StaticFile
+    }
+
+    fn prune(
+        &self,
+        provider: &Provider,
+        mut input: PruneInput,
+    ) -> Result<SegmentOutput, PrunerError> {
+        // Never prune senders of transactions that are still in the database, so cap the target
+        // block with the highest block which transactions were already pruned.
+        let Some(transactions_pruned_block) = provider
+            .get_prune_checkpoint(PruneSegment::Transactions)?
+            .and_then(|checkpoint| checkpoint.block_number)
+        else {
+            trace!(target: "pruner", "No transactions were pruned yet, skipping transaction senders");
+            return Ok(SegmentOutput::done())
+        };
+        input.to_block = input.to_block.min(transactions_pruned_block);
+
+        crate::segments::sender_recovery::prune(provider, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{PruneInput, Segment};
+    use alloy_primitives::B256;
+    use assert_matches::assert_matches;
+    use reth_db::tables;
+    use reth_provider::{
+        DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
+        StaticFileProviderFactory,
+    };
+    use reth_prune_types::{
+        PruneCheckpoint, PruneLimiter, PruneMode, PruneProgress, PruneSegment, SegmentOutput,
+    };
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    #[test]
+    fn prune_up_to_pruned_transactions() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut transaction_senders = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                transaction_senders.push((
+                    transaction_senders.len() as u64,
+                    transaction.recover_signer().expect("recover signer"),
+                ));
+            }
+        }
+        let transaction_senders_len = transaction_senders.len();
+        db.insert_transaction_senders(transaction_senders).expect("insert transaction senders");
+
+        let segment = super::TransactionSenders::new(db.factory.static_file_provider());
+        let input = |db: &TestStageDB| PruneInput {
+            previous_checkpoint: db
+                .factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::TransactionSenders)
+                .unwrap(),
+            to_block: 6,
+            limiter: PruneLimiter::default(),
+            dry_run: false,
+        };
+
+        // Transactions were not pruned yet, so no senders are pruned either
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input(&db)).unwrap();
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, pruned: 0, checkpoint: None, .. }
+        );
+        provider.commit().expect("commit");
+        assert_eq!(
+            db.table::<tables::TransactionSenders>().unwrap().len(),
+            transaction_senders_len
+        );
+
+        // Transactions were pruned up to block 3, so senders are pruned only up to block 3 too
+        let transactions_checkpoint_block = 3;
+        let last_tx_number = blocks
+            .iter()
+            .take(transactions_checkpoint_block)
+            .map(|block| block.body.transactions.len() as u64)
+            .sum::<u64>() -
+            1;
+        let provider = db.factory.database_provider_rw().unwrap();
+        provider
+            .save_prune_checkpoint(
+                PruneSegment::Transactions,
+                PruneCheckpoint {
+                    block_number: Some(transactions_checkpoint_block as u64),
+                    tx_number: Some(last_tx_number),
+                    prune_mode: PruneMode::Before(transactions_checkpoint_block as u64 + 1),
+                },
+            )
+            .unwrap();
+        let result = segment.prune(&provider, input(&db)).unwrap();
+        provider.commit().expect("commit");
+
+        assert_matches!(
+            result,
+            SegmentOutput { progress: PruneProgress::Finished, pruned, checkpoint: Some(checkpoint), .. }
+                if pruned == last_tx_number as usize + 1 &&
+                    checkpoint.block_number == Some(transactions_checkpoint_block as u64) &&
+                    checkpoint.tx_number == Some(last_tx_number)
+        );
+        assert_eq!(
+            db.table::<tables::TransactionSenders>().unwrap().len(),
+            transaction_senders_len - (last_tx_number as usize + 1)
+        );
+    }
+}
@@ -59,6 +59,7 @@ where
         let (pruned, done) = provider.tx_ref().prune_table_with_range::<tables::Transactions>(
             tx_range,
             &mut limiter,
+            input.dry_run,
             |_| false,
             |row| last_pruned_transaction = row.0,
         )?;
@@ -76,6 +77,7 @@ where
         Ok(SegmentOutput {
             progress,
             pruned,
+            pruned_blocks: None,
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: last_pruned_block,
                 tx_number: Some(last_pruned_transaction),
@@ -136,6 +138,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
 
             let next_tx_number_to_prune = db
@@ -154,7 +157,7 @@ mod tests {
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
 
@@ -84,6 +84,7 @@ where
             provider.tx_ref().prune_table_with_range::<tables::AccountChangeSets>(
                 range,
                 &mut limiter,
+                input.dry_run,
                 |_| false,
                 |(block_number, account)| {
                     highest_deleted_accounts.insert(account.address, block_number);
@@ -110,6 +111,7 @@ where
             provider,
             highest_sharded_keys,
             |a, b| a.key == b.key,
+            input.dry_run,
         )?;
         trace!(target: "pruner", ?outcomes, %done, "Pruned account history (indices)");
 
@@ -118,6 +120,7 @@ where
         Ok(SegmentOutput {
             progress,
             pruned: pruned_changesets + outcomes.deleted,
+            pruned_blocks: None,
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: Some(last_changeset_pruned_block),
                 tx_number: None,
@@ -200,6 +203,7 @@ mod tests {
                         .unwrap(),
                     to_block,
                     limiter: limiter.clone(),
+                    dry_run: false,
                 };
                 let segment = AccountHistory::new(prune_mode);
 
@@ -209,7 +213,7 @@ mod tests {
 
                 assert_matches!(
                     result,
-                    SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                    SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                         if (progress, pruned) == expected_result
                 );
 
@@ -305,4 +309,55 @@ mod tests {
         test_prune(998, 2, (PruneProgress::Finished, 998));
         test_prune(1400, 3, (PruneProgress::Finished, 804));
     }
+
+    #[test]
+    fn prune_dry_run() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=5000,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..1, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let accounts = random_eoa_accounts(&mut rng, 2).into_iter().collect::<BTreeMap<_, _>>();
+
+        let (changesets, _) = random_changeset_range(
+            &mut rng,
+            blocks.iter(),
+            accounts.into_iter().map(|(addr, acc)| (addr, (acc, Vec::new()))),
+            0..0,
+            0..0,
+        );
+        db.insert_changesets(changesets.clone(), None).expect("insert changesets");
+        db.insert_history(changesets, None).expect("insert history");
+
+        let original_changesets_len = db.table::<tables::AccountChangeSets>().unwrap().len();
+        let original_shards = db.table::<tables::AccountsHistory>().unwrap();
+
+        let to_block = 1400;
+        let segment = AccountHistory::new(PruneMode::Before(to_block));
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block,
+            limiter: PruneLimiter::default().set_deleted_entries_limit(2000),
+            dry_run: true,
+        };
+
+        // A dry run counts the entries without modifying the tables
+        let provider = db.factory.database_provider_rw().unwrap();
+        let dry_run_result = segment.prune(&provider, input.clone()).unwrap();
+        provider.commit().expect("commit");
+        assert_eq!(db.table::<tables::AccountChangeSets>().unwrap().len(), original_changesets_len);
+        assert_eq!(db.table::<tables::AccountsHistory>().unwrap(), original_shards);
+
+        // It reports the same output as the actual run
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, PruneInput { dry_run: false, ..input }).unwrap();
+        provider.commit().expect("commit");
+        assert_eq!(dry_run_result, result);
+        assert!(db.table::<tables::AccountChangeSets>().unwrap().len() < original_changesets_len);
+    }
 }
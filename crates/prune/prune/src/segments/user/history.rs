@@ -22,13 +22,15 @@ pub(crate) struct PrunedIndices {
     pub(crate) unchanged: usize,
 }
 
-/// Prune history indices according to the provided list of highest sharded keys.
+/// Prune history indices according to the provided list of highest sharded keys. If `dry_run` is
+/// set, the shards are only counted without modifying them.
 ///
 /// Returns total number of deleted, updated and unchanged entities.
 pub(crate) fn prune_history_indices<Provider, T, SK>(
     provider: &Provider,
     highest_sharded_keys: impl IntoIterator<Item = T::Key>,
     key_matches: impl Fn(&T::Key, &T::Key) -> bool,
+    dry_run: bool,
 ) -> Result<PrunedIndices, DatabaseError>
 where
     Provider: DBProvider<Tx: DbTxMut>,
@@ -54,7 +56,7 @@ where
             };
 
             if key_matches(&key, &sharded_key) {
-                match prune_shard(&mut cursor, key, block_nums, to_block, &key_matches)? {
+                match prune_shard(&mut cursor, key, block_nums, to_block, &key_matches, dry_run)? {
                     PruneShardOutcome::Deleted => outcomes.deleted += 1,
                     PruneShardOutcome::Updated => outcomes.updated += 1,
                     PruneShardOutcome::Unchanged => outcomes.unchanged += 1,
@@ -78,12 +80,16 @@ where
 /// 2. If the shard has `highest_block_number` greater than the target block number for pruning,
 ///    filter block numbers inside the shard which are less than the target block number for
 ///    pruning.
+///
+/// If `dry_run` is set, the outcome is returned without modifying the shard, leaving the cursor at
+/// the same position.
 fn prune_shard<C, T, SK>(
     cursor: &mut C,
     key: T::Key,
     raw_blocks: RawValue<T::Value>,
     to_block: BlockNumber,
     key_matches: impl Fn(&T::Key, &T::Key) -> bool,
+    dry_run: bool,
 ) -> Result<PruneShardOutcome, DatabaseError>
 where
     C: DbCursorRO<RawTable<T>> + DbCursorRW<RawTable<T>>,
@@ -93,7 +99,9 @@ where
     // If shard consists only of block numbers less than the target one, delete shard
     // completely.
     if key.as_ref().highest_block_number <= to_block {
-        cursor.delete_current()?;
+        if !dry_run {
+            cursor.delete_current()?;
+        }
         Ok(PruneShardOutcome::Deleted)
     }
     // Shard contains block numbers that are higher than the target one, so we need to
@@ -122,10 +130,15 @@ where
                     // If current shard is the last shard for the sharded key that
                     // has previous shards, replace it with the previous shard.
                     Some((prev_key, prev_value)) if key_matches(&prev_key, &key) => {
-                        cursor.delete_current()?;
-                        // Upsert will replace the last shard for this sharded key with
-                        // the previous value.
-                        cursor.upsert(RawKey::new(key), prev_value)?;
+                        if dry_run {
+                            // Jump back to the original last shard
+                            cursor.next()?;
+                        } else {
+                            cursor.delete_current()?;
+                            // Upsert will replace the last shard for this sharded key with
+                            // the previous value.
+                            cursor.upsert(RawKey::new(key), prev_value)?;
+                        }
                         Ok(PruneShardOutcome::Updated)
                     }
                     // If there's no previous shard for this sharded key,
@@ -137,7 +150,9 @@ where
                             cursor.next()?;
                         }
                         // Delete shard.
-                        cursor.delete_current()?;
+                        if !dry_run {
+                            cursor.delete_current()?;
+                        }
                         Ok(PruneShardOutcome::Deleted)
                     }
                 }
@@ -145,14 +160,18 @@ where
             // If current shard is not the last shard for this sharded key,
             // just delete it.
             else {
-                cursor.delete_current()?;
+                if !dry_run {
+                    cursor.delete_current()?;
+                }
                 Ok(PruneShardOutcome::Deleted)
             }
         } else {
-            cursor.upsert(
-                RawKey::new(key),
-                RawValue::new(BlockNumberList::new_pre_sorted(higher_blocks)),
-            )?;
+            if !dry_run {
+                cursor.upsert(
+                    RawKey::new(key),
+                    RawValue::new(BlockNumberList::new_pre_sorted(higher_blocks)),
+                )?;
+            }
             Ok(PruneShardOutcome::Updated)
         }
     }
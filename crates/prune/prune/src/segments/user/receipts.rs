@@ -39,7 +39,20 @@ where
 
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        crate::segments::receipts::prune(provider, input)
+        crate::segments::receipts::prune(provider, input, self.mode.every_nth())
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune_range(
+        &self,
+        provider: &Provider,
+        input: &PruneInput,
+    ) -> Result<Option<SegmentOutput>, PrunerError> {
+        // Receipts of the retained blocks split the range to prune
+        if self.mode.every_nth().is_some() {
+            return Ok(None)
+        }
+        crate::segments::receipts::prune_range(provider, input).map(Some)
     }
 
     fn save_checkpoint(
@@ -144,6 +144,7 @@ where
             (deleted, done) = provider.tx_ref().prune_table_with_range::<tables::Receipts>(
                 tx_range,
                 &mut limiter,
+                input.dry_run,
                 |(tx_num, receipt)| {
                     let skip = num_addresses > 0 &&
                         receipt.logs.iter().any(|log| {
@@ -200,18 +201,20 @@ where
             .lowest_block_with_distance(input.to_block, initial_last_pruned_block)?
             .unwrap_or(to_block);
 
-        provider.save_prune_checkpoint(
-            PruneSegment::ContractLogs,
-            PruneCheckpoint {
-                block_number: Some(prune_mode_block.min(last_pruned_block.unwrap_or(u64::MAX))),
-                tx_number: last_pruned_transaction,
-                prune_mode: PruneMode::Before(prune_mode_block),
-            },
-        )?;
+        if !input.dry_run {
+            provider.save_prune_checkpoint(
+                PruneSegment::ContractLogs,
+                PruneCheckpoint {
+                    block_number: Some(prune_mode_block.min(last_pruned_block.unwrap_or(u64::MAX))),
+                    tx_number: last_pruned_transaction,
+                    prune_mode: PruneMode::Before(prune_mode_block),
+                },
+            )?;
+        }
 
         let progress = PruneProgress::new(done, &limiter);
 
-        Ok(SegmentOutput { progress, pruned, checkpoint: None })
+        Ok(SegmentOutput { progress, pruned, pruned_blocks: None, checkpoint: None })
     }
 }
 
@@ -306,6 +309,7 @@ mod tests {
                         .unwrap(),
                     to_block: tip,
                     limiter,
+                    dry_run: false,
                 },
             );
             provider.commit().expect("commit");
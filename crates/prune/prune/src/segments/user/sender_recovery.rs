@@ -1,14 +1,11 @@
 use crate::{
-    db_ext::DbTxPruneExt,
     segments::{PruneInput, Segment},
     PrunerError,
 };
-use reth_db::{tables, transaction::DbTxMut};
+use reth_db::transaction::DbTxMut;
 use reth_provider::{BlockReader, DBProvider, TransactionsProvider};
-use reth_prune_types::{
-    PruneMode, PruneProgress, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
-};
-use tracing::{instrument, trace};
+use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutput};
+use tracing::instrument;
 
 #[derive(Debug)]
 pub struct SenderRecovery {
@@ -39,44 +36,7 @@ where
 
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        let tx_range = match input.get_next_tx_num_range(provider)? {
-            Some(range) => range,
-            None => {
-                trace!(target: "pruner", "No transaction senders to prune");
-                return Ok(SegmentOutput::done())
-            }
-        };
-        let tx_range_end = *tx_range.end();
-
-        let mut limiter = input.limiter;
-
-        let mut last_pruned_transaction = tx_range_end;
-        let (pruned, done) =
-            provider.tx_ref().prune_table_with_range::<tables::TransactionSenders>(
-                tx_range,
-                &mut limiter,
-                |_| false,
-                |row| last_pruned_transaction = row.0,
-            )?;
-        trace!(target: "pruner", %pruned, %done, "Pruned transaction senders");
-
-        let last_pruned_block = provider
-            .transaction_block(last_pruned_transaction)?
-            .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
-            // If there's more transaction senders to prune, set the checkpoint block number to
-            // previous, so we could finish pruning its transaction senders on the next run.
-            .checked_sub(if done { 0 } else { 1 });
-
-        let progress = PruneProgress::new(done, &limiter);
-
-        Ok(SegmentOutput {
-            progress,
-            pruned,
-            checkpoint: Some(SegmentOutputCheckpoint {
-                block_number: last_pruned_block,
-                tx_number: Some(last_pruned_transaction),
-            }),
-        })
+        crate::segments::sender_recovery::prune(provider, input)
     }
 }
 
@@ -143,6 +103,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
 
             let next_tx_number_to_prune = db
@@ -180,13 +141,22 @@ mod tests {
                 .into_inner()
                 .0;
 
+            // A dry run counts the entries without deleting them
+            let senders_len = db.table::<tables::TransactionSenders>().unwrap().len();
+            let provider = db.factory.database_provider_rw().unwrap();
+            let dry_run_result =
+                segment.prune(&provider, PruneInput { dry_run: true, ..input.clone() }).unwrap();
+            provider.commit().expect("commit");
+            assert_eq!(db.table::<tables::TransactionSenders>().unwrap().len(), senders_len);
+
             let provider = db.factory.database_provider_rw().unwrap();
             let result = segment.prune(&provider, input).unwrap();
             limiter.increment_deleted_entries_count_by(result.pruned);
+            assert_eq!(dry_run_result, result);
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
 
@@ -84,6 +84,7 @@ where
             provider.tx_ref().prune_table_with_range::<tables::StorageChangeSets>(
                 BlockNumberAddress::range(range),
                 &mut limiter,
+                input.dry_run,
                 |_| false,
                 |(BlockNumberAddress((block_number, address)), entry)| {
                     highest_deleted_storages.insert((address, entry.key), block_number);
@@ -115,6 +116,7 @@ where
             provider,
             highest_sharded_keys,
             |a, b| a.address == b.address && a.sharded_key.key == b.sharded_key.key,
+            input.dry_run,
         )?;
         trace!(target: "pruner", ?outcomes, %done, "Pruned storage history (indices)");
 
@@ -123,6 +125,7 @@ where
         Ok(SegmentOutput {
             progress,
             pruned: pruned_changesets + outcomes.deleted,
+            pruned_blocks: None,
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: Some(last_changeset_pruned_block),
                 tx_number: None,
@@ -204,6 +207,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
             let segment = StorageHistory::new(prune_mode);
 
@@ -213,7 +217,7 @@ mod tests {
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
 
@@ -76,6 +76,7 @@ where
             provider.tx_ref().prune_table_with_iterator::<tables::TransactionHashNumbers>(
                 hashes,
                 &mut limiter,
+                input.dry_run,
                 |row| {
                     last_pruned_transaction =
                         Some(last_pruned_transaction.unwrap_or(row.1).max(row.1))
@@ -100,6 +101,7 @@ where
         Ok(SegmentOutput {
             progress,
             pruned,
+            pruned_blocks: None,
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: last_pruned_block,
                 tx_number: Some(last_pruned_transaction),
@@ -170,6 +172,7 @@ mod tests {
                     .unwrap(),
                 to_block,
                 limiter: limiter.clone(),
+                dry_run: false,
             };
 
             let next_tx_number_to_prune = db
@@ -213,7 +216,7 @@ mod tests {
 
             assert_matches!(
                 result,
-                SegmentOutput {progress, pruned, checkpoint: Some(_)}
+                SegmentOutput {progress, pruned, checkpoint: Some(_), ..}
                     if (progress, pruned) == expected_result
             );
 
@@ -17,7 +17,7 @@ mod target;
 
 pub use checkpoint::PruneCheckpoint;
 pub use limiter::PruneLimiter;
-pub use mode::PruneMode;
+pub use mode::{PruneEveryNth, PruneMode};
 pub use pruner::{
     PruneInterruptReason, PruneProgress, PrunedSegmentInfo, PrunerOutput, SegmentOutput,
     SegmentOutputCheckpoint,
@@ -47,6 +47,10 @@ impl PruneTimeLimit {
     fn is_limit_reached(&self) -> bool {
         self.start.elapsed() > self.limit
     }
+
+    fn time_left(&self) -> Duration {
+        self.limit.saturating_sub(self.start.elapsed())
+    }
 }
 
 impl PruneLimiter {
@@ -95,7 +99,7 @@ impl PruneLimiter {
 
     /// Returns the number of deleted entries left before the limit is reached.
     pub fn deleted_entries_limit_left(&self) -> Option<usize> {
-        self.deleted_entries_limit.as_ref().map(|limit| limit.limit - limit.deleted)
+        self.deleted_entries_limit.as_ref().map(|limit| limit.limit.saturating_sub(limit.deleted))
     }
 
     /// Returns the limit on the number of deleted entries (rows in the database).
@@ -115,6 +119,34 @@ impl PruneLimiter {
         self.time_limit.as_ref().is_some_and(|limit| limit.is_limit_reached())
     }
 
+    /// Returns the time left before the time limit is reached.
+    pub fn time_limit_left(&self) -> Option<Duration> {
+        self.time_limit.as_ref().map(|limit| limit.time_left())
+    }
+
+    /// Creates a limiter for a batch of at most `entries` entries (rows in the database) to
+    /// delete, additionally limited by the number of deleted entries left of this limiter.
+    ///
+    /// The time limit is shared with this limiter.
+    pub fn batch(&self, entries: usize) -> Self {
+        let limit = self.deleted_entries_limit_left().map_or(entries, |left| left.min(entries));
+        Self {
+            deleted_entries_limit: Some(PruneDeletedEntriesLimit::new(limit)),
+            time_limit: self.time_limit.clone(),
+        }
+    }
+
+    /// Creates a limiter with its own limit of `entries` entries (rows in the database) to delete,
+    /// independent of the number of deleted entries of this limiter.
+    ///
+    /// The time limit is shared with this limiter.
+    pub fn with_own_deleted_entries_limit(&self, entries: usize) -> Self {
+        Self {
+            deleted_entries_limit: Some(PruneDeletedEntriesLimit::new(entries)),
+            time_limit: self.time_limit.clone(),
+        }
+    }
+
     /// Returns `true` if any limit is reached.
     pub fn is_limit_reached(&self) -> bool {
         self.is_deleted_entries_limit_reached() || self.is_time_limit_reached()
@@ -176,6 +208,32 @@ mod tests {
         assert!(time_limit.is_limit_reached());
     }
 
+    #[test]
+    fn test_batch() {
+        let mut limiter = PruneLimiter::default().set_deleted_entries_limit(10);
+        limiter.increment_deleted_entries_count_by(4);
+
+        // The batch starts with no deleted entries
+        let batch = limiter.batch(5);
+        assert_eq!(batch.deleted_entries_limit(), Some(5));
+        assert_eq!(batch.deleted_entries_limit_left(), Some(5));
+
+        // The batch is limited by the deleted entries left
+        assert_eq!(limiter.batch(20).deleted_entries_limit(), Some(6));
+        assert_eq!(PruneLimiter::default().batch(20).deleted_entries_limit(), Some(20));
+    }
+
+    #[test]
+    fn test_with_own_deleted_entries_limit() {
+        let mut limiter = PruneLimiter::default().set_deleted_entries_limit(10);
+        limiter.increment_deleted_entries_count_by(8);
+
+        // The own limit isn't reduced by the entries deleted within this limiter
+        let own = limiter.with_own_deleted_entries_limit(5);
+        assert_eq!(own.deleted_entries_limit(), Some(5));
+        assert_eq!(own.deleted_entries_limit_left(), Some(5));
+    }
+
     #[test]
     fn test_set_deleted_entries_limit_initial_state() {
         let pruner = PruneLimiter::default().set_deleted_entries_limit(100);
@@ -307,6 +365,10 @@ mod tests {
         limiter.increment_deleted_entries_count_by(5); // Simulate deleting 5 entries
         assert_eq!(limiter.deleted_entries_limit_left(), Some(0)); // 5 - 5 = 0
 
+        // Test when more entries than the limit are deleted
+        limiter.increment_deleted_entries_count_by(2);
+        assert_eq!(limiter.deleted_entries_limit_left(), Some(0));
+
         // Test when limit is not set
         limiter = PruneLimiter::default(); // No limit set
         assert_eq!(limiter.deleted_entries_limit_left(), None); // Should be None
@@ -15,6 +15,29 @@ pub enum PruneMode {
     Distance(u64),
     /// Prune blocks before the specified block number. The specified block number is not pruned.
     Before(BlockNumber),
+    /// Prune blocks before the specified block number, except for every N-th block.
+    ///
+    /// Only supported for receipts.
+    #[serde(rename = "every_nth")]
+    EveryNth(PruneEveryNth),
+}
+
+/// Configuration of [`PruneMode::EveryNth`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Compact)]
+#[cfg_attr(any(test, feature = "test-utils"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(compact)]
+pub struct PruneEveryNth {
+    /// Blocks with numbers divisible by this value are retained. If zero, no blocks are retained.
+    pub keep_every: u64,
+    /// Prune blocks before the specified block number. The specified block number is not pruned.
+    pub before: BlockNumber,
+}
+
+impl PruneEveryNth {
+    /// Returns `true` if the block is retained regardless of the `before` block number.
+    pub const fn is_retained(&self, block: BlockNumber) -> bool {
+        matches!(block.checked_rem(self.keep_every), Some(0))
+    }
 }
 
 #[cfg(any(test, feature = "test-utils"))]
@@ -41,14 +64,26 @@ impl PruneMode {
         purpose: PrunePurpose,
     ) -> Result<Option<(BlockNumber, Self)>, PruneSegmentError> {
         let result = match self {
+            // Sparse retention is only supported by the user receipts segment
+            Self::EveryNth(_) if segment != PruneSegment::Receipts || purpose.is_static_file() => {
+                return Err(PruneSegmentError::Configuration(segment))
+            }
             Self::Full if segment.min_blocks(purpose) == 0 => Some((tip, *self)),
             Self::Distance(distance) if *distance > tip => None, // Nothing to prune yet
             Self::Distance(distance) if *distance >= segment.min_blocks(purpose) => {
                 Some((tip - distance, *self))
             }
-            Self::Before(n) if *n == tip + 1 && purpose.is_static_file() => Some((tip, *self)),
-            Self::Before(n) if *n > tip => None, // Nothing to prune yet
-            Self::Before(n) if tip - n >= segment.min_blocks(purpose) => {
+            Self::Before(n) | Self::EveryNth(PruneEveryNth { before: n, .. })
+                if *n == tip + 1 && purpose.is_static_file() =>
+            {
+                Some((tip, *self))
+            }
+            Self::Before(n) | Self::EveryNth(PruneEveryNth { before: n, .. }) if *n > tip => {
+                None // Nothing to prune yet
+            }
+            Self::Before(n) | Self::EveryNth(PruneEveryNth { before: n, .. })
+                if tip - n >= segment.min_blocks(purpose) =>
+            {
                 Some(((*n).saturating_sub(1), *self))
             }
             _ => return Err(PruneSegmentError::Configuration(segment)),
@@ -67,6 +102,7 @@ impl PruneMode {
                 block < tip - *distance
             }
             Self::Before(n) => *n > block,
+            Self::EveryNth(every_nth) => every_nth.before > block && !every_nth.is_retained(block),
         }
     }
 
@@ -79,12 +115,21 @@ impl PruneMode {
     pub const fn is_distance(&self) -> bool {
         matches!(self, Self::Distance(_))
     }
+
+    /// Returns the [`PruneEveryNth`] configuration if the prune mode is [`PruneMode::EveryNth`].
+    pub const fn every_nth(&self) -> Option<PruneEveryNth> {
+        match self {
+            Self::EveryNth(every_nth) => Some(*every_nth),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        PruneMode, PrunePurpose, PruneSegment, PruneSegmentError, MINIMUM_PRUNING_DISTANCE,
+        PruneEveryNth, PruneMode, PrunePurpose, PruneSegment, PruneSegmentError,
+        MINIMUM_PRUNING_DISTANCE,
     };
     use assert_matches::assert_matches;
     use serde::Deserialize;
@@ -114,6 +159,19 @@ mod tests {
                 Ok(Some(tip - MINIMUM_PRUNING_DISTANCE - 2)),
             ),
             (PruneMode::Before(tip - 1), Err(PruneSegmentError::Configuration(segment))),
+            // Nothing to prune
+            (PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip + 1 }), Ok(None)),
+            (
+                PruneMode::EveryNth(PruneEveryNth {
+                    keep_every: 100,
+                    before: tip - MINIMUM_PRUNING_DISTANCE,
+                }),
+                Ok(Some(tip - MINIMUM_PRUNING_DISTANCE - 1)),
+            ),
+            (
+                PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip - 1 }),
+                Err(PruneSegmentError::Configuration(segment)),
+            ),
         ];
 
         for (index, (mode, expected_result)) in tests.into_iter().enumerate() {
@@ -125,6 +183,16 @@ mod tests {
             );
         }
 
+        // Sparse retention is not supported by other segments
+        assert_eq!(
+            PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }).prune_target_block(
+                tip,
+                PruneSegment::SenderRecovery,
+                PrunePurpose::User
+            ),
+            Err(PruneSegmentError::Configuration(PruneSegment::SenderRecovery)),
+        );
+
         // Test for a scenario where there are no minimum blocks and Full can be used
         assert_eq!(
             PruneMode::Full.prune_target_block(tip, PruneSegment::Transactions, PrunePurpose::User),
@@ -151,6 +219,24 @@ mod tests {
             ),
             (PruneMode::Before(tip + 1), 1, should_prune),
             (PruneMode::Before(tip + 1), tip + 1, !should_prune),
+            (PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }), 1, should_prune),
+            (PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }), 0, !should_prune),
+            (
+                PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }),
+                200,
+                !should_prune,
+            ),
+            (
+                PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }),
+                201,
+                should_prune,
+            ),
+            (
+                PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: tip }),
+                tip + 1,
+                !should_prune,
+            ),
+            (PruneMode::EveryNth(PruneEveryNth { keep_every: 0, before: tip }), 200, should_prune),
         ];
 
         for (index, (mode, block, expected_result)) in tests.into_iter().enumerate() {
@@ -166,12 +252,14 @@ mod tests {
             b: Option<PruneMode>,
             c: Option<PruneMode>,
             d: Option<PruneMode>,
+            e: Option<PruneMode>,
         }
 
         let toml_str = r#"
         a = "full"
         b = { distance = 10 }
         c = { before = 20 }
+        e = { every_nth = { keep_every = 100, before = 30 } }
     "#;
 
         assert_matches!(
@@ -180,7 +268,8 @@ mod tests {
                 a: Some(PruneMode::Full),
                 b: Some(PruneMode::Distance(10)),
                 c: Some(PruneMode::Before(20)),
-                d: None
+                d: None,
+                e: Some(PruneMode::EveryNth(PruneEveryNth { keep_every: 100, before: 30 })),
             })
         );
     }
@@ -1,5 +1,6 @@
 use crate::{PruneCheckpoint, PruneLimiter, PruneMode, PruneSegment};
 use alloy_primitives::{BlockNumber, TxNumber};
+use std::collections::BTreeMap;
 
 /// Pruner run output.
 #[derive(Debug)]
@@ -8,11 +9,86 @@ pub struct PrunerOutput {
     pub progress: PruneProgress,
     /// Pruning output for each segment.
     pub segments: Vec<(PruneSegment, SegmentOutput)>,
+    /// Number of pruned entries per segment, sorted by the number of entries in descending order.
+    ///
+    /// Shows which segments consumed the most of the delete limit.
+    pub budget_consumers: Vec<(PruneSegment, usize)>,
+    /// Oldest retained block per pruned segment, i.e. the first block after the saved checkpoint.
+    ///
+    /// Segments that were never pruned retain all blocks and are not included.
+    pub oldest_retained: BTreeMap<PruneSegment, BlockNumber>,
+    /// Maximum number of entries the run was allowed to delete, i.e. the shared delete limit plus
+    /// the own delete limits of the segments that were pruned.
+    ///
+    /// Zero if the run was skipped, e.g. because `ExExes` are not ready yet.
+    pub effective_delete_limit: usize,
+    /// Number of entries the run could still delete within [`Self::effective_delete_limit`].
+    ///
+    /// Runs that are consistently left with zero are bound by the delete limit.
+    pub delete_limit_remaining: usize,
+    /// Whether any segment saved a checkpoint that differs from its previous one.
+    ///
+    /// A segment can advance its checkpoint without pruning any entries, e.g. over a range of
+    /// blocks without transactions.
+    pub checkpoints_updated: bool,
+    /// Whether the database transaction of the run was committed.
+    ///
+    /// Runs that neither pruned any entries nor updated any checkpoints are not committed, see
+    /// [`Self::has_changes`]. Always `false` if the caller owns the database transaction and is
+    /// responsible for committing it.
+    pub committed: bool,
+}
+
+impl PrunerOutput {
+    /// Returns `true` if any segment pruned at least one entry.
+    pub fn has_pruned_entries(&self) -> bool {
+        self.segments.iter().any(|(_, segment)| segment.pruned > 0)
+    }
+
+    /// Returns `true` if the run and every pruned segment finished pruning, i.e. there's no more
+    /// data to prune up to the tip of the run.
+    pub fn is_finished(&self) -> bool {
+        self.progress.is_finished() &&
+            self.segments.iter().all(|(_, segment)| segment.progress.is_finished())
+    }
+
+    /// Returns `true` if the run modified the database, i.e. pruned any entries or updated any
+    /// checkpoints.
+    pub fn has_changes(&self) -> bool {
+        self.has_pruned_entries() || self.checkpoints_updated
+    }
 }
 
 impl From<PruneProgress> for PrunerOutput {
     fn from(progress: PruneProgress) -> Self {
-        Self { progress, segments: Vec::new() }
+        Self {
+            progress,
+            segments: Vec::new(),
+            budget_consumers: Vec::new(),
+            oldest_retained: BTreeMap::new(),
+            effective_delete_limit: 0,
+            delete_limit_remaining: 0,
+            checkpoints_updated: false,
+            committed: false,
+        }
+    }
+}
+
+impl PrunedSegmentInfo {
+    /// Sums the number of pruned entries per segment from the provided stats, and returns them
+    /// sorted by the number of entries in descending order.
+    pub fn budget_consumers<'a>(
+        stats: impl IntoIterator<Item = &'a Self>,
+    ) -> Vec<(PruneSegment, usize)> {
+        let mut consumers = Vec::<(PruneSegment, usize)>::new();
+        for info in stats {
+            match consumers.iter_mut().find(|(segment, _)| *segment == info.segment) {
+                Some((_, pruned)) => *pruned += info.pruned,
+                None => consumers.push((info.segment, info.pruned)),
+            }
+        }
+        consumers.sort_by(|(_, a), (_, b)| b.cmp(a));
+        consumers
     }
 }
 
@@ -25,6 +101,9 @@ pub struct PrunedSegmentInfo {
     pub pruned: usize,
     /// Prune progress
     pub progress: PruneProgress,
+    /// Delete limit of the segment, if it has its own one instead of sharing the delete limit of
+    /// the run with other segments. The `pruned` entries are consumed from it.
+    pub delete_limit: Option<usize>,
 }
 
 /// Segment pruning output.
@@ -34,6 +113,9 @@ pub struct SegmentOutput {
     pub progress: PruneProgress,
     /// Number of entries pruned, i.e. deleted from the database.
     pub pruned: usize,
+    /// Number of blocks pruned, if the segment tracks it explicitly. Otherwise, the number of
+    /// pruned blocks is derived from the difference between the previous and the new checkpoint.
+    pub pruned_blocks: Option<u64>,
     /// Pruning checkpoint to save to database, if any.
     pub checkpoint: Option<SegmentOutputCheckpoint>,
 }
@@ -42,7 +124,7 @@ impl SegmentOutput {
     /// Returns a [`SegmentOutput`] with `done = true`, `pruned = 0` and `checkpoint = None`.
     /// Use when no pruning is needed.
     pub const fn done() -> Self {
-        Self { progress: PruneProgress::Finished, pruned: 0, checkpoint: None }
+        Self { progress: PruneProgress::Finished, pruned: 0, pruned_blocks: None, checkpoint: None }
     }
 
     /// Returns a [`SegmentOutput`] with `done = false`, `pruned = 0` and `checkpoint = None`.
@@ -51,7 +133,12 @@ impl SegmentOutput {
         reason: PruneInterruptReason,
         checkpoint: Option<SegmentOutputCheckpoint>,
     ) -> Self {
-        Self { progress: PruneProgress::HasMoreData(reason), pruned: 0, checkpoint }
+        Self {
+            progress: PruneProgress::HasMoreData(reason),
+            pruned: 0,
+            pruned_blocks: None,
+            checkpoint,
+        }
     }
 }
 
@@ -92,6 +179,8 @@ pub enum PruneInterruptReason {
     Timeout,
     /// Limit on the number of deleted entries (rows in the database) per prune run was reached.
     DeletedEntriesLimitReached,
+    /// Prune run was cancelled, e.g. because the node is shutting down.
+    Cancelled,
     /// Unknown reason for stopping prune run.
     Unknown,
 }
@@ -117,6 +206,11 @@ impl PruneInterruptReason {
     pub const fn is_entries_limit_reached(&self) -> bool {
         matches!(self, Self::DeletedEntriesLimitReached)
     }
+
+    /// Returns `true` if the prune run was cancelled.
+    pub const fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled)
+    }
 }
 
 impl PruneProgress {
@@ -138,3 +232,47 @@ impl PruneProgress {
         matches!(self, Self::Finished)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_consumers() {
+        let stats = [
+            PrunedSegmentInfo {
+                segment: PruneSegment::SenderRecovery,
+                pruned: 10,
+                progress: PruneProgress::Finished,
+                delete_limit: None,
+            },
+            PrunedSegmentInfo {
+                segment: PruneSegment::Receipts,
+                pruned: 30,
+                progress: PruneProgress::Finished,
+                delete_limit: None,
+            },
+            PrunedSegmentInfo {
+                segment: PruneSegment::SenderRecovery,
+                pruned: 25,
+                progress: PruneProgress::Finished,
+                delete_limit: None,
+            },
+            PrunedSegmentInfo {
+                segment: PruneSegment::Headers,
+                pruned: 5,
+                progress: PruneProgress::Finished,
+                delete_limit: None,
+            },
+        ];
+
+        assert_eq!(
+            PrunedSegmentInfo::budget_consumers(&stats),
+            vec![
+                (PruneSegment::SenderRecovery, 35),
+                (PruneSegment::Receipts, 30),
+                (PruneSegment::Headers, 5)
+            ]
+        );
+    }
+}
@@ -11,6 +11,8 @@ use reth_primitives::{Receipt, SealedBlockWithSenders};
 use reth_storage_api::BlockReader;
 use std::sync::Arc;
 
+pub use reth_storage_api::PreparedFilter;
+
 /// Returns all matching of a block's receipts when the transaction hashes are known.
 pub fn matching_block_logs_with_tx_hashes<'a, I>(
     filter: &FilteredParams,
@@ -179,10 +181,53 @@ pub fn get_filter_block_range(
 
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::{Address, Bloom, BloomInput, B256};
     use alloy_rpc_types_eth::Filter;
 
     use super::*;
 
+    #[test]
+    fn prepared_filter_matches_filtered_params() {
+        let (address, other_address) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let (topic, other_topic) = (B256::with_last_byte(3), B256::with_last_byte(4));
+        let bloom = |inputs: &[&[u8]]| {
+            let mut bloom = Bloom::default();
+            for input in inputs {
+                bloom.accrue(BloomInput::Raw(input));
+            }
+            bloom
+        };
+        let blooms = [
+            Bloom::default(),
+            bloom(&[address.as_slice()]),
+            bloom(&[address.as_slice(), topic.as_slice()]),
+            bloom(&[other_address.as_slice(), topic.as_slice()]),
+            bloom(&[address.as_slice(), other_topic.as_slice()]),
+        ];
+        let filters = [
+            Filter::new(),
+            Filter::new().address(address),
+            Filter::new().address(vec![address, other_address]),
+            Filter::new().address(address).topic1(topic),
+            Filter::new().topic0(vec![topic, other_topic]).topic2(topic),
+        ];
+
+        for filter in filters {
+            let address_filter = FilteredParams::address_filter(&filter.address);
+            let topics_filter = FilteredParams::topics_filter(&filter.topics);
+            let prepared = PreparedFilter::new(&FilteredParams::new(Some(filter.clone())));
+            for bloom in blooms {
+                assert_eq!(
+                    prepared.matches(bloom),
+                    FilteredParams::matches_address(bloom, &address_filter) &&
+                        FilteredParams::matches_topics(bloom, &topics_filter),
+                    "{filter:?} {bloom:?}"
+                );
+            }
+        }
+        assert!(PreparedFilter::new(&FilteredParams::new(None)).matches(Bloom::default()));
+    }
+
     #[test]
     fn test_log_range_from_and_to() {
         let from = 14000000u64;
@@ -64,6 +64,7 @@ where
                         pruned,
                         checkpoint:
                             checkpoint @ Some(SegmentOutputCheckpoint { block_number: Some(_), .. }),
+                        ..
                     } => {
                         info!(
                             target: "sync::stages::prune::exec",
@@ -74,7 +75,7 @@ where
                             "Last segment has more data to prune"
                         )
                     }
-                    SegmentOutput { progress, pruned, checkpoint: _ } => {
+                    SegmentOutput { progress, pruned, .. } => {
                         info!(
                             target: "sync::stages::prune::exec",
                             ?last_segment,
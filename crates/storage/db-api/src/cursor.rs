@@ -5,7 +5,7 @@ use std::{
 
 use crate::{
     common::{IterPairResult, PairResult, ValueOnlyResult},
-    table::{DupSort, Table, TableRow},
+    table::{DupSort, KeyPrefix, Table, TableRow},
     DatabaseError,
 };
 
@@ -59,6 +59,27 @@ pub trait DbCursorRO<T: Table> {
     ) -> Result<ReverseWalker<'_, T, Self>, DatabaseError>
     where
         Self: Sized;
+
+    /// Get an iterator that walks over a range of keys in the table in reverse order.
+    ///
+    /// The walker starts at the greatest key within the upper bound of `range` and yields entries
+    /// in descending key order until the lower bound is crossed. It yields the same entries as
+    /// [`DbCursorRO::walk_range`], reversed.
+    fn walk_back_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<ReverseRangeWalker<'_, T, Self>, DatabaseError>
+    where
+        Self: Sized;
+
+    /// Get an iterator that walks over all entries whose key starts with `prefix`.
+    ///
+    /// The walker starts at the first key with the given prefix and stops at the first key that
+    /// no longer has it. See [`KeyPrefix`] for how the prefix of a composite key is defined.
+    fn walk_prefix<P>(&mut self, prefix: P) -> Result<PrefixWalker<'_, T, Self, P>, DatabaseError>
+    where
+        Self: Sized,
+        T::Key: KeyPrefix<P>;
 }
 
 /// A read-only cursor over the dup table `T`.
@@ -319,6 +340,176 @@ impl<T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> RangeWalker<'_, T, CURSOR>
     }
 }
 
+/// Provides a reverse range iterator to `Cursor` when handling `Table`.
+/// Also check [`RangeWalker`]
+pub struct ReverseRangeWalker<'cursor, T: Table, CURSOR: DbCursorRO<T>> {
+    /// Cursor to be used to walk through the table.
+    cursor: &'cursor mut CURSOR,
+    /// `(key, value)` where to start the walk.
+    start: IterPairResult<T>,
+    /// `key` where to stop the walk.
+    start_key: Bound<T::Key>,
+    /// flag whether is ended
+    is_done: bool,
+}
+
+impl<T, CURSOR> fmt::Debug for ReverseRangeWalker<'_, T, CURSOR>
+where
+    T: Table,
+    CURSOR: DbCursorRO<T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReverseRangeWalker")
+            .field("cursor", &self.cursor)
+            .field("start", &self.start)
+            .field("start_key", &self.start_key)
+            .field("is_done", &self.is_done)
+            .finish()
+    }
+}
+
+impl<T: Table, CURSOR: DbCursorRO<T>> Iterator for ReverseRangeWalker<'_, T, CURSOR> {
+    type Item = Result<TableRow<T>, DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None
+        }
+
+        let next_item = self.start.take().or_else(|| self.cursor.prev().transpose());
+
+        match next_item {
+            Some(Ok((key, value))) => match &self.start_key {
+                Bound::Included(start_key) if &key >= start_key => Some(Ok((key, value))),
+                Bound::Excluded(start_key) if &key > start_key => Some(Ok((key, value))),
+                Bound::Unbounded => Some(Ok((key, value))),
+                _ => {
+                    self.is_done = true;
+                    None
+                }
+            },
+            Some(res @ Err(_)) => Some(res),
+            None => {
+                self.is_done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'cursor, T: Table, CURSOR: DbCursorRO<T>> ReverseRangeWalker<'cursor, T, CURSOR> {
+    /// construct `ReverseRangeWalker`
+    ///
+    /// `start` is the entry with the greatest key within the upper bound of the range, and
+    /// `start_key` is the lower bound of the range.
+    pub fn new(
+        cursor: &'cursor mut CURSOR,
+        start: IterPairResult<T>,
+        start_key: Bound<T::Key>,
+    ) -> Self {
+        // mark done if range is empty.
+        let is_done = match start {
+            Some(Ok((ref key, _))) => match &start_key {
+                Bound::Included(start_key) if key < start_key => true,
+                Bound::Excluded(start_key) if key <= start_key => true,
+                _ => false,
+            },
+            None => true,
+            _ => false,
+        };
+        Self { cursor, start, start_key, is_done }
+    }
+}
+
+impl<T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>> ReverseRangeWalker<'_, T, CURSOR> {
+    /// Delete current item that walker points to.
+    pub fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        self.start.take();
+        self.cursor.delete_current()
+    }
+}
+
+/// Provides an iterator over the entries sharing a key prefix to `Cursor` when handling `Table`.
+/// Also check [`Walker`]
+pub struct PrefixWalker<'cursor, T: Table, CURSOR: DbCursorRO<T>, P> {
+    /// Cursor to be used to walk through the table.
+    cursor: &'cursor mut CURSOR,
+    /// `(key, value)` where to start the walk.
+    start: IterPairResult<T>,
+    /// Prefix that all yielded keys start with.
+    prefix: P,
+    /// flag whether is ended
+    is_done: bool,
+}
+
+impl<T, CURSOR, P> fmt::Debug for PrefixWalker<'_, T, CURSOR, P>
+where
+    T: Table,
+    CURSOR: DbCursorRO<T> + fmt::Debug,
+    P: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrefixWalker")
+            .field("cursor", &self.cursor)
+            .field("start", &self.start)
+            .field("prefix", &self.prefix)
+            .field("is_done", &self.is_done)
+            .finish()
+    }
+}
+
+impl<T, CURSOR, P> Iterator for PrefixWalker<'_, T, CURSOR, P>
+where
+    T: Table,
+    T::Key: KeyPrefix<P>,
+    CURSOR: DbCursorRO<T>,
+{
+    type Item = Result<TableRow<T>, DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None
+        }
+
+        let next_item = self.start.take().or_else(|| self.cursor.next().transpose());
+
+        match next_item {
+            Some(Ok((key, value))) if key.has_prefix(&self.prefix) => Some(Ok((key, value))),
+            Some(res @ Err(_)) => Some(res),
+            _ => {
+                self.is_done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'cursor, T, CURSOR, P> PrefixWalker<'cursor, T, CURSOR, P>
+where
+    T: Table,
+    T::Key: KeyPrefix<P>,
+    CURSOR: DbCursorRO<T>,
+{
+    /// construct `PrefixWalker`
+    pub fn new(cursor: &'cursor mut CURSOR, start: IterPairResult<T>, prefix: P) -> Self {
+        // mark done if no key has the prefix.
+        let is_done = match start {
+            Some(Ok((ref key, _))) => !key.has_prefix(&prefix),
+            None => true,
+            _ => false,
+        };
+        Self { cursor, start, prefix, is_done }
+    }
+}
+
+impl<T: Table, CURSOR: DbCursorRW<T> + DbCursorRO<T>, P> PrefixWalker<'_, T, CURSOR, P> {
+    /// Delete current item that walker points to.
+    pub fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        self.start.take();
+        self.cursor.delete_current()
+    }
+}
+
 /// Provides an iterator to `Cursor` when handling a `DupSort` table.
 ///
 /// Reason why we have two lifetimes is to distinguish between `'cursor` lifetime
@@ -19,8 +19,9 @@
 //! - **Cursors** ([`DbCursorRO`] / [`DbCursorRW`]) for iterating data in a table. Cursors are
 //!   assumed to resolve data in a sorted manner when iterating from start to finish, and it is safe
 //!   to assume that they are efficient at doing so.
-//! - **Walkers** ([`Walker`] / [`RangeWalker`] / [`ReverseWalker`]) use cursors to walk the entries
-//!   in a table, either fully from a specific point, or over a range.
+//! - **Walkers** ([`Walker`] / [`RangeWalker`] / [`ReverseWalker`] / [`ReverseRangeWalker`] /
+//!   [`PrefixWalker`]) use cursors to walk the entries in a table, either fully from a specific
+//!   point, over a range, or over all keys sharing a prefix.
 //!
 //! Dup tables (see below) also have corresponding cursors and walkers (e.g. [`DbDupCursorRO`]).
 //! These **should** be preferred when working with dup tables, as they provide additional methods
@@ -44,6 +45,8 @@
 //! [`Walker`]: crate::cursor::Walker
 //! [`RangeWalker`]: crate::cursor::RangeWalker
 //! [`ReverseWalker`]: crate::cursor::ReverseWalker
+//! [`ReverseRangeWalker`]: crate::cursor::ReverseRangeWalker
+//! [`PrefixWalker`]: crate::cursor::PrefixWalker
 //! [`DbDupCursorRO`]: crate::cursor::DbDupCursorRO
 //! [`Encode`]: crate::table::Encode
 //! [`Decode`]: crate::table::Decode
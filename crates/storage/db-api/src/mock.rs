@@ -3,11 +3,11 @@
 use crate::{
     common::{IterPairResult, PairResult, ValueOnlyResult},
     cursor::{
-        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
-        ReverseWalker, Walker,
+        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, PrefixWalker, RangeWalker,
+        ReverseRangeWalker, ReverseWalker, Walker,
     },
     database::Database,
-    table::{DupSort, Table, TableImporter},
+    table::{DupSort, KeyPrefix, Table, TableImporter},
     transaction::{DbTx, DbTxMut},
     DatabaseError,
 };
@@ -177,6 +177,41 @@ impl<T: Table> DbCursorRO<T> for CursorMock {
         };
         Ok(ReverseWalker::new(self, start))
     }
+
+    fn walk_back_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<ReverseRangeWalker<'_, T, Self>, DatabaseError> {
+        let start: IterPairResult<T> = match range.end_bound().cloned() {
+            Bound::Included(key) => {
+                let mut entry = <Self as DbCursorRO<T>>::seek(self, key.clone())?;
+                while entry.as_ref().is_some_and(|(found, _)| *found == key) {
+                    entry = <Self as DbCursorRO<T>>::next(self)?;
+                }
+                match entry {
+                    Some(_) => <Self as DbCursorRO<T>>::prev(self).transpose(),
+                    None => <Self as DbCursorRO<T>>::last(self).transpose(),
+                }
+            }
+            Bound::Excluded(key) => match <Self as DbCursorRO<T>>::seek(self, key)? {
+                Some(_) => <Self as DbCursorRO<T>>::prev(self).transpose(),
+                None => <Self as DbCursorRO<T>>::last(self).transpose(),
+            },
+            Bound::Unbounded => <Self as DbCursorRO<T>>::last(self).transpose(),
+        };
+
+        Ok(ReverseRangeWalker::new(self, start, range.start_bound().cloned()))
+    }
+
+    fn walk_prefix<P>(&mut self, prefix: P) -> Result<PrefixWalker<'_, T, Self, P>, DatabaseError>
+    where
+        T::Key: KeyPrefix<P>,
+    {
+        let start =
+            <Self as DbCursorRO<T>>::seek(self, <T::Key as KeyPrefix<P>>::prefix_start(&prefix))
+                .transpose();
+        Ok(PrefixWalker::new(self, start, prefix))
+    }
 }
 
 impl<T: DupSort> DbDupCursorRO<T> for CursorMock {
@@ -4,7 +4,7 @@ use std::ops::{Range, RangeInclusive};
 
 use crate::{
     impl_fixed_arbitrary,
-    table::{Decode, Encode},
+    table::{Decode, Encode, KeyPrefix},
     DatabaseError,
 };
 use alloy_primitives::{Address, BlockNumber, StorageKey};
@@ -71,6 +71,16 @@ impl Decode for BlockNumberAddress {
     }
 }
 
+impl KeyPrefix<BlockNumber> for BlockNumberAddress {
+    fn prefix_start(prefix: &BlockNumber) -> Self {
+        Self((*prefix, Address::ZERO))
+    }
+
+    fn has_prefix(&self, prefix: &BlockNumber) -> bool {
+        self.block_number() == *prefix
+    }
+}
+
 /// [`Address`] concatenated with [`StorageKey`]. Used by `reth_etl` and history stages.
 ///
 /// Since it's used as a key, it isn't compressed when encoding it.
@@ -102,6 +112,16 @@ impl Decode for AddressStorageKey {
     }
 }
 
+impl KeyPrefix<Address> for AddressStorageKey {
+    fn prefix_start(prefix: &Address) -> Self {
+        Self((*prefix, StorageKey::ZERO))
+    }
+
+    fn has_prefix(&self, prefix: &Address) -> bool {
+        self.0 .0 == *prefix
+    }
+}
+
 impl_fixed_arbitrary!((BlockNumberAddress, 28), (AddressStorageKey, 52));
 
 #[cfg(test)]
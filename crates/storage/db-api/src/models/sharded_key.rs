@@ -1,9 +1,9 @@
 //! Sharded key
 use crate::{
-    table::{Decode, Encode},
+    table::{Decode, Encode, KeyPrefix},
     DatabaseError,
 };
-use alloy_primitives::BlockNumber;
+use alloy_primitives::{Address, BlockNumber};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 
@@ -61,3 +61,13 @@ impl<T: Decode> Decode for ShardedKey<T> {
         Ok(Self::new(key, highest_tx_number))
     }
 }
+
+impl KeyPrefix<Address> for ShardedKey<Address> {
+    fn prefix_start(prefix: &Address) -> Self {
+        Self::new(*prefix, 0)
+    }
+
+    fn has_prefix(&self, prefix: &Address) -> bool {
+        self.key == *prefix
+    }
+}
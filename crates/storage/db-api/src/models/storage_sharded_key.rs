@@ -1,6 +1,6 @@
 //! Storage sharded key
 use crate::{
-    table::{Decode, Encode},
+    table::{Decode, Encode, KeyPrefix},
     DatabaseError,
 };
 use alloy_primitives::{Address, BlockNumber, B256};
@@ -81,3 +81,23 @@ impl Decode for StorageShardedKey {
         Ok(Self { address, sharded_key: ShardedKey::new(storage_key, highest_tx_number) })
     }
 }
+
+impl KeyPrefix<Address> for StorageShardedKey {
+    fn prefix_start(prefix: &Address) -> Self {
+        Self::new(*prefix, B256::ZERO, 0)
+    }
+
+    fn has_prefix(&self, prefix: &Address) -> bool {
+        self.address == *prefix
+    }
+}
+
+impl KeyPrefix<(Address, B256)> for StorageShardedKey {
+    fn prefix_start((address, storage_key): &(Address, B256)) -> Self {
+        Self::new(*address, *storage_key, 0)
+    }
+
+    fn has_prefix(&self, (address, storage_key): &(Address, B256)) -> bool {
+        self.address == *address && self.sharded_key.key == *storage_key
+    }
+}
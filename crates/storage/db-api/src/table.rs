@@ -71,6 +71,19 @@ pub trait Key: Encode + Decode + Ord + Clone + Serialize + for<'a> Deserialize<'
 
 impl<T> Key for T where T: Encode + Decode + Ord + Clone + Serialize + for<'a> Deserialize<'a> {}
 
+/// A composite [`Key`] whose encoding starts with the encoding of a leading component `P`.
+///
+/// Implementors guarantee that the encoded prefix has a fixed length, so all keys sharing a prefix
+/// are stored contiguously and can be walked with
+/// [`DbCursorRO::walk_prefix`](crate::cursor::DbCursorRO::walk_prefix).
+pub trait KeyPrefix<P>: Key {
+    /// Returns the smallest key starting with `prefix`.
+    fn prefix_start(prefix: &P) -> Self;
+
+    /// Returns `true` if the key starts with `prefix`.
+    fn has_prefix(&self, prefix: &P) -> bool;
+}
+
 /// Generic trait that enforces the database value to implement [`Compress`] and [`Decompress`].
 pub trait Value: Compress + Decompress + Serialize {}
 
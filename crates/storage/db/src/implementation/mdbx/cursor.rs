@@ -8,10 +8,10 @@ use crate::{
 use reth_db_api::{
     common::{PairResult, ValueOnlyResult},
     cursor::{
-        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
-        ReverseWalker, Walker,
+        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, PrefixWalker, RangeWalker,
+        ReverseRangeWalker, ReverseWalker, Walker,
     },
-    table::{Compress, Decode, Decompress, DupSort, Encode, Table},
+    table::{Compress, Decode, Decompress, DupSort, Encode, KeyPrefix, Table},
 };
 use reth_libmdbx::{Error as MDBXError, TransactionKind, WriteFlags, RO, RW};
 use reth_storage_errors::db::{DatabaseErrorInfo, DatabaseWriteError, DatabaseWriteOperation};
@@ -155,6 +155,46 @@ impl<K: TransactionKind, T: Table> DbCursorRO<T> for Cursor<K, T> {
 
         Ok(ReverseWalker::new(self, start))
     }
+
+    fn walk_back_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<ReverseRangeWalker<'_, T, Self>, DatabaseError> {
+        // Position the cursor at the first entry past the end of the range and step back once.
+        let start = match range.end_bound().cloned() {
+            Bound::Included(key) => {
+                let mut entry = self.seek(key.clone())?;
+                // Skip over all duplicates of the end key in case of a dup table.
+                while entry.as_ref().is_some_and(|(found, _)| *found == key) {
+                    entry = self.next()?;
+                }
+                if entry.is_some() {
+                    self.prev()
+                } else {
+                    self.last()
+                }
+            }
+            Bound::Excluded(key) => {
+                if self.seek(key)?.is_some() {
+                    self.prev()
+                } else {
+                    self.last()
+                }
+            }
+            Bound::Unbounded => self.last(),
+        }
+        .transpose();
+
+        Ok(ReverseRangeWalker::new(self, start, range.start_bound().cloned()))
+    }
+
+    fn walk_prefix<P>(&mut self, prefix: P) -> Result<PrefixWalker<'_, T, Self, P>, DatabaseError>
+    where
+        T::Key: KeyPrefix<P>,
+    {
+        let start = self.seek(<T::Key as KeyPrefix<P>>::prefix_start(&prefix)).transpose();
+        Ok(PrefixWalker::new(self, start, prefix))
+    }
 }
 
 impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
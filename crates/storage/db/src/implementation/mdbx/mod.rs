@@ -493,6 +493,7 @@ mod tests {
     use crate::{
         tables::{
             AccountsHistory, CanonicalHeaders, Headers, PlainAccountState, PlainStorageState,
+            StoragesHistory,
         },
         test_utils::*,
         AccountChangeSets,
@@ -501,14 +502,17 @@ mod tests {
     use alloy_primitives::{Address, B256, U256};
     use reth_db_api::{
         cursor::{DbDupCursorRO, DbDupCursorRW, ReverseWalker, Walker},
-        models::{AccountBeforeTx, ShardedKey},
+        models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
         table::{Encode, Table},
     };
     use reth_libmdbx::Error;
     use reth_primitives::{Account, StorageEntry};
     use reth_primitives_traits::IntegerList;
     use reth_storage_errors::db::{DatabaseWriteError, DatabaseWriteOperation};
-    use std::str::FromStr;
+    use std::{
+        ops::{Bound, RangeBounds},
+        str::FromStr,
+    };
     use tempfile::TempDir;
 
     /// Create database for testing
@@ -760,6 +764,158 @@ mod tests {
         assert_eq!(walker.next(), None);
     }
 
+    #[allow(clippy::reversed_empty_ranges)]
+    #[test]
+    fn db_cursor_walk_back_range() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        // PUT (0, 0), (1, 1), (3, 3), (5, 5)
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        vec![0, 1, 3, 5]
+            .into_iter()
+            .try_for_each(|key| tx.put::<CanonicalHeaders>(key, B256::with_last_byte(key as u8)))
+            .expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+
+        // [1, 5)
+        let mut walker = cursor.walk_back_range(1..5).unwrap();
+        assert_eq!(walker.next(), Some(Ok((3, B256::with_last_byte(3)))));
+        assert_eq!(walker.next(), Some(Ok((1, B256::with_last_byte(1)))));
+        assert_eq!(walker.next(), None);
+        // next() returns None after walker is done
+        assert_eq!(walker.next(), None);
+
+        // (1, 5]
+        let mut walker = cursor.walk_back_range((Bound::Excluded(1), Bound::Included(5))).unwrap();
+        assert_eq!(walker.next(), Some(Ok((5, B256::with_last_byte(5)))));
+        assert_eq!(walker.next(), Some(Ok((3, B256::with_last_byte(3)))));
+        assert_eq!(walker.next(), None);
+
+        fn assert_reverse_of_walk_range(
+            cursor: &mut impl DbCursorRO<CanonicalHeaders>,
+            range: impl RangeBounds<u64> + Clone + std::fmt::Debug,
+        ) {
+            let mut forward =
+                cursor.walk_range(range.clone()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+            forward.reverse();
+            let backward =
+                cursor.walk_back_range(range.clone()).unwrap().collect::<Result<Vec<_>, _>>();
+            assert_eq!(backward, Ok(forward), "range {range:?}");
+        }
+
+        assert_reverse_of_walk_range(&mut cursor, ..);
+        assert_reverse_of_walk_range(&mut cursor, 0..5);
+        assert_reverse_of_walk_range(&mut cursor, 0..=5);
+        assert_reverse_of_walk_range(&mut cursor, 1..=3);
+        assert_reverse_of_walk_range(&mut cursor, 2..=4);
+        assert_reverse_of_walk_range(&mut cursor, 2..3);
+        assert_reverse_of_walk_range(&mut cursor, 3..=3);
+        assert_reverse_of_walk_range(&mut cursor, 3..3);
+        assert_reverse_of_walk_range(&mut cursor, ..3);
+        assert_reverse_of_walk_range(&mut cursor, ..=3);
+        assert_reverse_of_walk_range(&mut cursor, 4..);
+        assert_reverse_of_walk_range(&mut cursor, 6..);
+        assert_reverse_of_walk_range(&mut cursor, 2..=10);
+        assert_reverse_of_walk_range(&mut cursor, 3..1);
+    }
+
+    #[test]
+    fn db_cursor_walk_back_range_on_dup_table() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        for block in 0..3 {
+            for address in [Address::ZERO, Address::with_last_byte(1), Address::with_last_byte(2)] {
+                tx.put::<AccountChangeSets>(block, AccountBeforeTx { address, info: None })
+                    .expect(ERROR_PUT);
+            }
+        }
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<AccountChangeSets>().unwrap();
+
+        // all duplicates of the end key are included, in reverse order
+        for range in [0..=1, 1..=2, 0..=0, 2..=2] {
+            let mut forward =
+                cursor.walk_range(range.clone()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+            forward.reverse();
+            let backward =
+                cursor.walk_back_range(range).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(backward, forward);
+        }
+    }
+
+    #[test]
+    fn db_cursor_walk_prefix() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+
+        let addresses = [1, 2, 3].map(Address::with_last_byte);
+        let slots = [1, 2].map(B256::with_last_byte);
+
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        for address in addresses {
+            for slot in slots {
+                for shard in [100, 200] {
+                    tx.put::<StoragesHistory>(
+                        StorageShardedKey::new(address, slot, shard),
+                        IntegerList::new_pre_sorted([shard]),
+                    )
+                    .expect(ERROR_PUT);
+                }
+            }
+        }
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = db.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_read::<StoragesHistory>().unwrap();
+
+        // all shards of all slots of one address
+        let keys = cursor
+            .walk_prefix(addresses[1])
+            .unwrap()
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                StorageShardedKey::new(addresses[1], slots[0], 100),
+                StorageShardedKey::new(addresses[1], slots[0], 200),
+                StorageShardedKey::new(addresses[1], slots[1], 100),
+                StorageShardedKey::new(addresses[1], slots[1], 200),
+            ]
+        );
+
+        // all shards of one slot
+        let keys = cursor
+            .walk_prefix((addresses[2], slots[0]))
+            .unwrap()
+            .map(|entry| entry.map(|(key, _)| key))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                StorageShardedKey::new(addresses[2], slots[0], 100),
+                StorageShardedKey::new(addresses[2], slots[0], 200),
+            ]
+        );
+
+        // prefixes before, between and after the stored keys
+        let mut walker = cursor.walk_prefix(Address::ZERO).unwrap();
+        assert_eq!(walker.next(), None);
+        let mut walker = cursor.walk_prefix((addresses[0], B256::ZERO)).unwrap();
+        assert_eq!(walker.next(), None);
+        let mut walker = cursor.walk_prefix(Address::with_last_byte(4)).unwrap();
+        assert_eq!(walker.next(), None);
+        // next() returns None after walker is done
+        assert_eq!(walker.next(), None);
+    }
+
     #[test]
     fn db_walker() {
         let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
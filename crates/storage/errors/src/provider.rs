@@ -2,7 +2,9 @@ use crate::{db::DatabaseError, lockfile::StorageLockError, writer::UnifiedStorag
 use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxNumber, B256, U256};
 use derive_more::Display;
-use reth_primitives::{GotExpected, StaticFileSegment, TxHashOrNumber};
+use reth_primitives::{
+    BodyValidationError, GotExpected, GotExpectedBoxed, StaticFileSegment, TxHashOrNumber,
+};
 
 #[cfg(feature = "std")]
 use std::path::PathBuf;
@@ -19,6 +21,9 @@ pub enum ProviderError {
     Database(DatabaseError),
     /// RLP error.
     Rlp(alloy_rlp::Error),
+    /// I/O error while reading or writing data outside of the database, e.g. exported blocks.
+    #[display("I/O error: {_0}")]
+    Io(String),
     /// Filesystem path error.
     #[display("{_0}")]
     FsPathError(String),
@@ -87,6 +92,12 @@ pub enum ProviderError {
         /// The transaction ID.
         tx_id: TxNumber,
     },
+    /// The parent hash of the block doesn't match the hash of the preceding block.
+    #[display("parent hash mismatch of block #{_0}: {_1}")]
+    ParentHashMismatch(BlockNumber, GotExpectedBoxed<B256>),
+    /// The block body doesn't match its header.
+    #[display("invalid block body: {_0}")]
+    BlockBodyValidation(Box<BodyValidationError>),
     /// Block body wrong transaction count.
     #[display("stored block indices does not match transaction count")]
     BlockBodyTransactionCount,
@@ -139,12 +150,26 @@ pub enum ProviderError {
     /// Static File Provider was initialized as read-only.
     #[display("cannot get a writer on a read-only environment.")]
     ReadOnlyStaticFileAccess,
+    /// The data was moved between the database and static files after the provider was opened,
+    /// so its view is stale. The provider should be reopened to retry the read.
+    #[display("{_0} data at #{_1} was moved since the provider was opened, reopen it to retry")]
+    StaleView(StaticFileSegment, u64),
     /// Error encountered when the block number conversion from U256 to u64 causes an overflow.
     #[display("failed to convert block number U256 to u64: {_0}")]
     BlockNumberOverflow(U256),
     /// Consistent view error.
     #[display("failed to initialize consistent view: {_0}")]
     ConsistentView(Box<ConsistentViewError>),
+    /// The block range of a query spans more blocks than allowed.
+    #[display("block range #{from_block}..=#{to_block} exceeds the limit of {max_blocks} blocks")]
+    BlockRangeTooLarge {
+        /// First block of the range.
+        from_block: BlockNumber,
+        /// Last block of the range.
+        to_block: BlockNumber,
+        /// Maximum number of blocks in the range.
+        max_blocks: u64,
+    },
     /// Storage lock error.
     StorageLockError(StorageLockError),
     /// Storage writer error.
@@ -175,6 +200,12 @@ impl From<UnifiedStorageWriterError> for ProviderError {
     }
 }
 
+impl From<BodyValidationError> for ProviderError {
+    fn from(error: BodyValidationError) -> Self {
+        Self::BlockBodyValidation(Box::new(error))
+    }
+}
+
 impl core::error::Error for ProviderError {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
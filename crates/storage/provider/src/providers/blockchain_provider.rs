@@ -1,11 +1,11 @@
 #![allow(unused)]
 use crate::{
     providers::{ConsistentProvider, StaticFileProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
-    ChainSpecProvider, ChainStateBlockReader, ChangeSetReader, DatabaseProvider,
-    DatabaseProviderFactory, EvmEnvProvider, FullProvider, HeaderProvider, ProviderError,
-    ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
+    to_range, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader,
+    BlockReaderIdExt, BlockSource, CanonChainTracker, CanonStateNotifications,
+    CanonStateSubscriptions, ChainSpecProvider, ChainStateBlockReader, ChangeSetReader,
+    DatabaseProvider, DatabaseProviderFactory, EvmEnvProvider, FullProvider, HeaderProvider,
+    ProviderError, ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StateReader,
     StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
@@ -16,6 +16,7 @@ use alloy_eips::{
 };
 use alloy_primitives::{Address, BlockHash, BlockNumber, Sealable, TxHash, TxNumber, B256, U256};
 use alloy_rpc_types_engine::ForkchoiceState;
+use alloy_rpc_types_eth::{Filter, Log};
 use reth_chain_state::{
     BlockState, CanonicalInMemoryState, ForkChoiceNotifications, ForkChoiceSubscriptions,
     MemoryOverlayStateProvider,
@@ -32,7 +33,9 @@ use reth_primitives::{
 };
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
-use reth_storage_api::{DBProvider, StorageChangeSetReader};
+use reth_storage_api::{
+    logs_by_header_blooms, receipts_with_tx_hash_chunks, DBProvider, StorageChangeSetReader,
+};
 use reth_storage_errors::provider::ProviderResult;
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
@@ -413,6 +416,17 @@ impl<N: ProviderNodeTypes> ReceiptProvider for BlockchainProvider2<N> {
     ) -> ProviderResult<Vec<Receipt>> {
         self.consistent_provider()?.receipts_by_tx_range(range)
     }
+
+    fn receipts_with_tx_hash_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(B256, Receipt)>>> {
+        Ok(receipts_with_tx_hash_chunks(self.consistent_provider()?, to_range(range)))
+    }
+
+    fn logs_by_filter(&self, filter: &Filter) -> ProviderResult<Vec<Log>> {
+        logs_by_header_blooms(&self.consistent_provider()?, filter)
+    }
 }
 
 impl<N: ProviderNodeTypes> ReceiptProviderIdExt for BlockchainProvider2<N> {
@@ -774,7 +788,8 @@ mod tests {
         StaticFileWriter,
     };
     use alloy_eips::{eip4895::Withdrawals, BlockHashOrNumber, BlockNumHash, BlockNumberOrTag};
-    use alloy_primitives::{BlockNumber, TxNumber, B256};
+    use alloy_primitives::{Address, BlockNumber, Bloom, BloomInput, TxNumber, B256};
+    use alloy_rpc_types_eth::{Filter, Log};
     use itertools::Itertools;
     use rand::Rng;
     use reth_chain_state::{
@@ -788,15 +803,21 @@ mod tests {
         models::{AccountBeforeTx, StoredBlockBodyIndices},
         tables,
     };
-    use reth_db_api::{cursor::DbCursorRO, transaction::DbTx};
+    use reth_db_api::{
+        cursor::DbCursorRO,
+        transaction::{DbTx, DbTxMut},
+    };
     use reth_errors::ProviderError;
     use reth_execution_types::{Chain, ExecutionOutcome};
-    use reth_primitives::{Receipt, SealedBlock, StaticFileSegment, TransactionSignedNoHash};
+    use reth_primitives::{
+        proofs::calculate_receipt_root_no_memo, Receipt, ReceiptWithBloom, SealedBlock,
+        SealedHeader, StaticFileSegment, TransactionSignedNoHash,
+    };
     use reth_storage_api::{
-        BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource,
-        ChangeSetReader, DatabaseProviderFactory, HeaderProvider, ReceiptProvider,
-        ReceiptProviderIdExt, StateProviderFactory, TransactionVariant, TransactionsProvider,
-        WithdrawalsProvider,
+        BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderExt,
+        BlockReaderIdExt, BlockSource, ChangeSetReader, DatabaseProviderFactory, HeaderProvider,
+        ReceiptProvider, ReceiptProviderIdExt, StateProviderFactory, TransactionVariant,
+        TransactionsProvider, WithdrawalsProvider,
     };
     use reth_testing_utils::generators::{
         self, random_block, random_block_range, random_changeset_range, random_eoa_accounts,
@@ -1382,6 +1403,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_header_provider_headers_page() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams::default(),
+        )?;
+        let headers = [database_blocks, in_memory_blocks]
+            .concat()
+            .into_iter()
+            .map(|block| block.header)
+            .collect::<Vec<_>>();
+
+        // Pages span both database and in-memory blocks
+        let (page, next) = provider.headers_page(None, 4)?;
+        assert_eq!((page.as_slice(), next), (&headers[..4], Some(3)));
+        let (page, next) = provider.headers_page(next, 4)?;
+        assert_eq!((page.as_slice(), next), (&headers[4..8], Some(7)));
+        let (page, next) = provider.headers_page(next, 4)?;
+        assert_eq!((page.as_slice(), next), (&headers[8..], None));
+
+        let (page, next) = provider.headers_page(Some(headers.len() as u64), 4)?;
+        assert_eq!((page, next), (Vec::new(), None));
+
+        // A zero limit ends the pagination instead of returning the same cursor
+        let (page, next) = provider.headers_page(Some(3), 0)?;
+        assert_eq!((page, next), (Vec::new(), None));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_canon_state_subscriptions() -> eyre::Result<()> {
         let factory = create_test_provider_factory();
@@ -1780,6 +1834,461 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_receipt_provider_id_ext_calculate_receipts_root() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, receipts) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )?;
+
+        let receipts_root = |number: BlockNumber| {
+            calculate_receipt_root_no_memo(&receipts[number as usize].iter().collect::<Vec<_>>())
+        };
+
+        let database_block = database_blocks.first().unwrap().clone();
+        let canonical_block = in_memory_blocks.last().unwrap().clone();
+
+        assert_eq!(
+            provider.calculate_receipts_root(database_block.number.into())?,
+            Some(receipts_root(database_block.number))
+        );
+        assert_eq!(
+            provider.calculate_receipts_root(BlockNumberOrTag::Latest)?,
+            Some(receipts_root(canonical_block.number))
+        );
+        assert_eq!(provider.calculate_receipts_root((canonical_block.number + 1).into())?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_receipt_provider_receipts_with_tx_hash_range() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )?;
+
+        let mut expected = Vec::new();
+        for block in database_blocks.iter().chain(&in_memory_blocks) {
+            let receipts = provider.receipts_by_block(block.number.into())?.unwrap();
+            expected.extend(block.body.transactions.iter().map(|tx| tx.hash()).zip(receipts));
+        }
+
+        // Receipts span the database and in-memory blocks, in transaction order
+        let receipts = provider.receipts_with_tx_hash_range(..)?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(receipts, expected);
+
+        let receipts =
+            provider.receipts_with_tx_hash_range(1..3)?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(receipts, expected[1..3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_receipt_provider_raw_receipt_by_id() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )?;
+
+        let tx_count = database_blocks
+            .iter()
+            .chain(&in_memory_blocks)
+            .map(|block| block.body.transactions.len() as u64)
+            .sum::<u64>();
+        for id in 0..tx_count {
+            let raw = provider.raw_receipt_by_id(id)?.unwrap();
+
+            // Typed receipts are decoded from an RLP string, legacy receipts from the list itself
+            let encoded = if raw[0] < alloy_rlp::EMPTY_LIST_CODE {
+                alloy_rlp::encode(&raw)
+            } else {
+                raw.to_vec()
+            };
+            let decoded =
+                <ReceiptWithBloom as alloy_rlp::Decodable>::decode(&mut encoded.as_slice())?;
+            assert_eq!(Some(decoded.receipt), provider.receipt(id)?);
+        }
+        assert_eq!(provider.raw_receipt_by_id(tx_count)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_cumulative_gas_used() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 0..3, ..Default::default() },
+        )?;
+
+        let mut expected = Vec::new();
+        for block in database_blocks.iter().chain(&in_memory_blocks) {
+            let receipts = provider.receipts_by_block(block.number.into())?.unwrap();
+            let gas_used = receipts.last().map_or(0, |receipt| receipt.cumulative_gas_used);
+            assert_eq!(provider.block_gas_used(block.number)?, Some(gas_used));
+            expected.push((block.number, gas_used));
+        }
+
+        // Gas used spans the database and in-memory blocks, and stops at the last block
+        let first_block = database_blocks.first().unwrap().number;
+        let last_block = in_memory_blocks.last().unwrap().number;
+        assert_eq!(provider.cumulative_gas_used(first_block..=last_block + 1)?, expected);
+        assert_eq!(provider.cumulative_gas_used(first_block + 1..first_block + 3)?, expected[1..3]);
+        assert_eq!(provider.block_gas_used(last_block + 1)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_block_summaries() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 0..3, ..Default::default() },
+        )?;
+
+        let expected = database_blocks
+            .iter()
+            .chain(&in_memory_blocks)
+            .map(|block| BlockSummary {
+                number: block.number,
+                hash: block.hash(),
+                timestamp: block.timestamp,
+                gas_used: block.gas_used,
+                gas_limit: block.gas_limit,
+                tx_count: block.body.transactions.len() as u64,
+                base_fee: block.base_fee_per_gas,
+            })
+            .collect::<Vec<_>>();
+
+        // Summaries span the database and in-memory blocks
+        let first_block = database_blocks.first().unwrap().number;
+        assert_eq!(provider.block_summaries(first_block..)?, expected);
+        assert_eq!(provider.block_summaries(first_block + 1..first_block + 3)?, expected[1..3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_header_provider_header_ancestors() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams::default(),
+        )?;
+
+        let ancestors = database_blocks
+            .iter()
+            .chain(&in_memory_blocks)
+            .rev()
+            .skip(1)
+            .map(|block| block.header.clone())
+            .collect::<Vec<_>>();
+        let tip_hash = in_memory_blocks.last().unwrap().hash();
+
+        // Ancestors span the in-memory and database blocks
+        assert_eq!(
+            provider.header_ancestors(tip_hash, TEST_BLOCKS_COUNT + 1)?,
+            ancestors[..TEST_BLOCKS_COUNT + 1]
+        );
+
+        // Stops at genesis
+        assert_eq!(provider.header_ancestors(tip_hash, usize::MAX)?, ancestors);
+        assert_eq!(provider.header_ancestors(database_blocks[0].hash(), 1)?, vec![]);
+
+        let unknown_hash = B256::random();
+        assert!(matches!(
+            provider.header_ancestors(unknown_hash, 1),
+            Err(ProviderError::HeaderNotFound(hash)) if hash == unknown_hash.into()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_latest_blocks() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams::default(),
+        )?;
+
+        let newest_first =
+            database_blocks.iter().chain(&in_memory_blocks).rev().cloned().collect::<Vec<_>>();
+        assert_eq!(provider.latest_blocks(0)?, vec![]);
+        assert_eq!(provider.latest_blocks(3)?, newest_first[..3]);
+        assert_eq!(
+            provider.latest_blocks(TEST_BLOCKS_COUNT + 1)?,
+            newest_first[..TEST_BLOCKS_COUNT + 1]
+        );
+
+        // Clamped to the chain length
+        assert_eq!(provider.latest_blocks(usize::MAX)?, newest_first);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_block_body_parts() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 1..3, ..Default::default() },
+        )?;
+
+        for block in [database_blocks.first().unwrap(), in_memory_blocks.last().unwrap()] {
+            let body = provider.block(block.number.into())?.unwrap().body;
+            assert_eq!(
+                provider.block_body_parts(block.number)?,
+                Some((body.transactions, body.ommers, body.withdrawals))
+            );
+        }
+        assert_eq!(provider.block_body_parts(in_memory_blocks.last().unwrap().number + 1)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_id_ext_transaction_and_receipt() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 2..4, ..Default::default() },
+        )?;
+
+        for block in [database_blocks.last().unwrap(), in_memory_blocks.last().unwrap()] {
+            let expected = provider.block_receipts_with_tx_meta(block.number.into())?.unwrap();
+            for (transaction, receipt, meta) in expected {
+                assert_eq!(
+                    provider.transaction_and_receipt(transaction.hash())?,
+                    Some((transaction, receipt, meta))
+                );
+            }
+        }
+        assert_eq!(provider.transaction_and_receipt(B256::random())?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_reader_id_ext_transaction_and_receipt_partially_pruned() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let factory = create_test_provider_factory();
+
+        let block =
+            random_block(&mut rng, 0, BlockParams { tx_count: Some(3), ..Default::default() });
+
+        // Receipt of the first transaction was pruned
+        let provider_rw = factory.provider_rw()?;
+        provider_rw.insert_historical_block(
+            block.clone().seal_with_senders().expect("failed to seal block with senders"),
+        )?;
+        for (tx_number, tx) in block.body.transactions.iter().enumerate().skip(1) {
+            provider_rw.tx_ref().put::<tables::Receipts>(
+                tx_number as TxNumber,
+                random_receipt(&mut rng, tx, Some(1)),
+            )?;
+        }
+        provider_rw.commit()?;
+
+        let provider = BlockchainProvider2::new(factory)?;
+
+        // The metadata of the remaining receipts can't be computed without the pruned one
+        for tx in &block.body.transactions {
+            assert_eq!(provider.transaction_and_receipt(tx.hash())?, None);
+        }
+
+        Ok(())
+    }
+
+    /// Same as [`provider_with_random_blocks`], but the logs blooms of the block headers are set
+    /// from their receipts, so that the blocks can be filtered by their logs.
+    #[allow(clippy::type_complexity)]
+    fn provider_with_random_blocks_and_logs_blooms(
+        rng: &mut impl Rng,
+        database_blocks: usize,
+        in_memory_blocks: usize,
+        tx_count: Range<u8>,
+    ) -> eyre::Result<(
+        BlockchainProvider2<MockNodeTypesWithDB>,
+        Vec<SealedBlock>,
+        Vec<SealedBlock>,
+        Vec<Vec<Receipt>>,
+    )> {
+        let (mut database_blocks, mut in_memory_blocks) =
+            random_blocks(rng, database_blocks, in_memory_blocks, None, None, tx_count);
+        let receipts: Vec<Vec<_>> = database_blocks
+            .iter()
+            .chain(in_memory_blocks.iter())
+            .map(|block| block.body.transactions.iter().map(|tx| random_receipt(rng, tx, Some(2))))
+            .map(Iterator::collect)
+            .collect();
+
+        // Changing the logs blooms changes the block hashes, so the parent hashes are relinked
+        let mut parent_hash = B256::ZERO;
+        for (block, block_receipts) in
+            database_blocks.iter_mut().chain(in_memory_blocks.iter_mut()).zip(&receipts)
+        {
+            let mut header = block.header.clone().unseal();
+            header.parent_hash = parent_hash;
+            header.logs_bloom = block_receipts.iter().fold(Bloom::ZERO, |mut bloom, receipt| {
+                bloom.accrue_bloom(&receipt.bloom_slow());
+                bloom
+            });
+            block.header = SealedHeader::seal(header);
+            parent_hash = block.hash();
+        }
+
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.database_provider_rw()?;
+        let static_file_provider = factory.static_file_provider();
+
+        // Insert blocks into the database, writing their transactions to static files
+        let mut tx_num = 0;
+        for block in &database_blocks {
+            let mut transactions_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Transactions)?;
+            transactions_writer.increment_block(block.number)?;
+            for tx in block.body.transactions() {
+                let tx: TransactionSignedNoHash = tx.clone().into();
+                transactions_writer.append_transaction(tx_num, &tx)?;
+                tx_num += 1;
+            }
+
+            provider_rw.insert_historical_block(
+                block.clone().seal_with_senders().expect("failed to seal block with senders"),
+            )?;
+        }
+
+        // Insert receipts into the static files
+        UnifiedStorageWriter::new(
+            &provider_rw,
+            Some(static_file_provider.latest_writer(StaticFileSegment::Receipts)?),
+        )
+        .append_receipts_from_blocks(
+            0,
+            receipts[..database_blocks.len()]
+                .iter()
+                .map(|vec| vec.clone().into_iter().map(Some).collect::<Vec<_>>()),
+        )?;
+        UnifiedStorageWriter::commit(provider_rw)?;
+
+        // Insert the rest of the blocks and receipts into the in-memory state
+        let provider = BlockchainProvider2::new(factory)?;
+        let chain = NewCanonicalChain::Commit {
+            new: in_memory_blocks
+                .iter()
+                .map(|block| {
+                    let senders = block.senders().expect("failed to recover senders");
+                    let execution_outcome = ExecutionOutcome {
+                        receipts: receipts[block.number as usize].clone().into(),
+                        ..Default::default()
+                    };
+
+                    ExecutedBlock::new(
+                        Arc::new(block.clone()),
+                        Arc::new(senders),
+                        execution_outcome.into(),
+                        Default::default(),
+                        Default::default(),
+                    )
+                })
+                .collect(),
+        };
+        provider.canonical_in_memory_state.update_chain(chain);
+        provider.set_canonical_head(in_memory_blocks.last().unwrap().header.clone());
+
+        Ok((provider, database_blocks, in_memory_blocks, receipts))
+    }
+
+    #[test]
+    fn test_receipt_provider_logs_by_filter() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, receipts) =
+            provider_with_random_blocks_and_logs_blooms(
+                &mut rng,
+                TEST_BLOCKS_COUNT,
+                TEST_BLOCKS_COUNT,
+                2..4,
+            )?;
+
+        let mut expected = Vec::new();
+        for block in database_blocks.iter().chain(&in_memory_blocks) {
+            let logs = receipts[block.number as usize]
+                .iter()
+                .zip(&block.body.transactions)
+                .enumerate()
+                .flat_map(|(index, (receipt, transaction))| {
+                    receipt.logs.iter().map(move |log| (index, transaction.hash(), log))
+                });
+            for (log_index, (index, transaction_hash, log)) in logs.enumerate() {
+                expected.push(Log {
+                    inner: log.clone(),
+                    block_hash: Some(block.hash()),
+                    block_number: Some(block.number),
+                    block_timestamp: Some(block.header.timestamp),
+                    transaction_hash: Some(transaction_hash),
+                    transaction_index: Some(index as u64),
+                    log_index: Some(log_index as u64),
+                    removed: false,
+                });
+            }
+        }
+        assert!(!expected.is_empty());
+
+        // Logs span the database and in-memory blocks
+        let all_blocks = Filter::new().from_block(BlockNumberOrTag::Earliest);
+        assert_eq!(provider.logs_by_filter(&all_blocks)?, expected);
+
+        let tip = in_memory_blocks.last().unwrap();
+        assert_eq!(
+            provider.logs_by_filter(&Filter::new().at_block_hash(tip.hash()))?,
+            expected
+                .iter()
+                .filter(|log| log.block_number == Some(tip.number))
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+
+        // Only the blocks whose logs blooms contain the address are searched
+        let address = expected[0].inner.address;
+        let matching =
+            expected.iter().filter(|log| log.inner.address == address).cloned().collect::<Vec<_>>();
+        assert_eq!(provider.logs_by_filter(&all_blocks.clone().address(address))?, matching);
+        let blocks = database_blocks.iter().chain(&in_memory_blocks).collect::<Vec<_>>();
+        assert!(blocks
+            .iter()
+            .any(|block| !block.logs_bloom.contains_input(BloomInput::Raw(address.as_slice()))));
+
+        // No header bloom contains an unknown address, so no receipts match
+        assert_eq!(provider.logs_by_filter(&all_blocks.address(Address::ZERO))?, vec![]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_changeset_reader() -> eyre::Result<()> {
         let mut rng = generators::rng();
@@ -15,7 +15,7 @@ use alloy_eips::{
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
 use core::fmt;
 use reth_chainspec::{ChainInfo, EthereumHardforks};
-use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv};
+use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv, Tables};
 use reth_db_api::{database::Database, models::StoredBlockBodyIndices};
 use reth_errors::{RethError, RethResult};
 use reth_evm::ConfigureEvmEnv;
@@ -26,10 +26,11 @@ use reth_primitives::{
 };
 use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
-use reth_storage_api::TryIntoHistoricalStateProvider;
-use reth_storage_errors::provider::ProviderResult;
+use reth_storage_api::{receipts_with_tx_hash_chunks, TryIntoHistoricalStateProvider};
+use reth_storage_errors::{db::DatabaseError, provider::ProviderResult};
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
+    collections::BTreeMap,
     ops::{RangeBounds, RangeInclusive},
     path::Path,
     sync::Arc,
@@ -61,6 +62,8 @@ pub struct ProviderFactory<N: NodeTypesWithDB> {
     prune_modes: PruneModes,
     /// The node storage handler.
     storage: Arc<N::Storage>,
+    /// Whether read-only providers record the static file boundaries at open time.
+    migration_guard: bool,
 }
 
 impl<N> fmt::Debug for ProviderFactory<N>
@@ -68,13 +71,15 @@ where
     N: NodeTypesWithDB<DB: fmt::Debug, ChainSpec: fmt::Debug, Storage: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { db, chain_spec, static_file_provider, prune_modes, storage } = self;
+        let Self { db, chain_spec, static_file_provider, prune_modes, storage, migration_guard } =
+            self;
         f.debug_struct("ProviderFactory")
             .field("db", &db)
             .field("chain_spec", &chain_spec)
             .field("static_file_provider", &static_file_provider)
             .field("prune_modes", &prune_modes)
             .field("storage", &storage)
+            .field("migration_guard", &migration_guard)
             .finish()
     }
 }
@@ -92,6 +97,7 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
             static_file_provider,
             prune_modes: PruneModes::none(),
             storage: Default::default(),
+            migration_guard: false,
         }
     }
 
@@ -107,6 +113,20 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Makes read-only providers returned by [`ProviderFactory::provider`] record the static file
+    /// boundaries of all segments when they are opened.
+    ///
+    /// A guarded provider returns [`ProviderError::StaleView`] instead of reading from the wrong
+    /// source if a read would touch data that was moved between the database and static files
+    /// after the provider was opened. Recover by dropping the provider and opening a new one with
+    /// [`ProviderFactory::provider`], which records the new boundaries.
+    ///
+    /// [`ProviderError::StaleView`]: reth_storage_errors::provider::ProviderError::StaleView
+    pub const fn with_migration_guard(mut self) -> Self {
+        self.migration_guard = true;
+        self
+    }
+
     /// Returns reference to the underlying database.
     pub const fn db_ref(&self) -> &N::DB {
         &self.db
@@ -134,8 +154,47 @@ impl<N: NodeTypesWithDB<DB = Arc<DatabaseEnv>>> ProviderFactory<N> {
             static_file_provider,
             prune_modes: PruneModes::none(),
             storage: Default::default(),
+            migration_guard: false,
         })
     }
+
+    /// Create new provider factory that serves data only from the read-only static files located
+    /// at `static_files_path`, without a pre-existing database.
+    ///
+    /// Headers, transactions and receipts covered by static files are read from them. The factory
+    /// still needs a database for data that only lives there (e.g. state, block body indices or
+    /// hash lookups), so an empty one is created at `db_path`, and such reads return `None`
+    /// instead of failing. The caller owns `db_path` and is responsible for removing it.
+    pub fn read_only_static<P: AsRef<Path>>(
+        static_files_path: P,
+        db_path: P,
+        chain_spec: Arc<N::ChainSpec>,
+    ) -> ProviderResult<Self> {
+        let static_file_provider = StaticFileProvider::read_only(static_files_path, true)?;
+        let db = init_db(db_path, DatabaseArguments::new(Default::default()))
+            .map_err(|err| DatabaseError::Other(err.to_string()))?;
+
+        Ok(Self::new(Arc::new(db), chain_spec, static_file_provider))
+    }
+
+    /// Returns the size in bytes of every database table, keyed by table name.
+    ///
+    /// The size is calculated from the number of leaf, branch and overflow pages used by the table,
+    /// so it includes the MDBX page overhead. Static files are not included.
+    pub fn table_sizes(&self) -> ProviderResult<BTreeMap<String, u64>> {
+        let tx = self.db.tx()?;
+        let mut sizes = BTreeMap::new();
+        for table in Tables::ALL.iter().map(Tables::name) {
+            let table_db =
+                tx.inner.open_db(Some(table)).map_err(|err| DatabaseError::Open(err.into()))?;
+            let stats =
+                tx.inner.db_stat(&table_db).map_err(|err| DatabaseError::Stats(err.into()))?;
+
+            let num_pages = stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages();
+            sizes.insert(table.to_string(), (stats.page_size() as usize * num_pages) as u64);
+        }
+        Ok(sizes)
+    }
 }
 
 impl<N: ProviderNodeTypes> ProviderFactory<N> {
@@ -147,10 +206,15 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
     /// data.
     #[track_caller]
     pub fn provider(&self) -> ProviderResult<DatabaseProviderRO<N::DB, N>> {
+        let static_file_provider = if self.migration_guard {
+            self.static_file_provider.with_migration_guard()
+        } else {
+            self.static_file_provider.clone()
+        };
         Ok(DatabaseProvider::new(
             self.db.tx()?,
             self.chain_spec.clone(),
-            self.static_file_provider.clone(),
+            static_file_provider,
             self.prune_modes.clone(),
             self.storage.clone(),
         ))
@@ -240,6 +304,10 @@ impl<N: ProviderNodeTypes> HeaderProvider for ProviderFactory<N> {
         self.provider()?.header(block_hash)
     }
 
+    fn headers_by_hashes(&self, hashes: &[BlockHash]) -> ProviderResult<Vec<Option<Header>>> {
+        self.provider()?.headers_by_hashes(hashes)
+    }
+
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
         self.static_file_provider.get_with_static_file_or_database(
             StaticFileSegment::Headers,
@@ -287,6 +355,10 @@ impl<N: ProviderNodeTypes> HeaderProvider for ProviderFactory<N> {
         )
     }
 
+    fn canonical_hash(&self, number: BlockNumber) -> ProviderResult<Option<BlockHash>> {
+        self.block_hash(number)
+    }
+
     fn sealed_headers_range(
         &self,
         range: impl RangeBounds<BlockNumber>,
@@ -384,6 +456,10 @@ impl<N: ProviderNodeTypes> BlockReader for ProviderFactory<N> {
         self.provider()?.block_body_indices(number)
     }
 
+    fn block_range_available(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.provider()?.block_range_available()
+    }
+
     fn block_with_senders(
         &self,
         id: BlockHashOrNumber,
@@ -523,6 +599,13 @@ impl<N: ProviderNodeTypes> ReceiptProvider for ProviderFactory<N> {
             |_| true,
         )
     }
+
+    fn receipts_with_tx_hash_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(B256, Receipt)>>> {
+        Ok(receipts_with_tx_hash_chunks(self.provider()?, to_range(range)))
+    }
 }
 
 impl<N: ProviderNodeTypes> WithdrawalsProvider for ProviderFactory<N> {
@@ -633,6 +716,7 @@ impl<N: NodeTypesWithDB> Clone for ProviderFactory<N> {
             static_file_provider: self.static_file_provider.clone(),
             prune_modes: self.prune_modes.clone(),
             storage: self.storage.clone(),
+            migration_guard: self.migration_guard,
         }
     }
 }
@@ -643,8 +727,8 @@ mod tests {
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
         test_utils::{blocks::TEST_BLOCK, create_test_provider_factory, MockNodeTypesWithDB},
-        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderSyncGapProvider,
-        TransactionsProvider,
+        AccountExtReader, BlockHashReader, BlockNumReader, BlockReaderExt, BlockWriter, DBProvider,
+        HeaderSyncGapProvider, PruneCheckpointWriter, StateProvider, TransactionsProvider,
     };
     use alloy_primitives::{TxNumber, B256, U256};
     use assert_matches::assert_matches;
@@ -655,10 +739,16 @@ mod tests {
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
     };
-    use reth_primitives::StaticFileSegment;
+    use reth_db_api::transaction::DbTxMut;
+    use reth_primitives::{
+        Account, BodyValidationError, EthPrimitives, GotExpected, StaticFileSegment,
+    };
     use reth_prune_types::{PruneMode, PruneModes};
+    use reth_storage_api::write_block_with_senders;
     use reth_storage_errors::provider::ProviderError;
-    use reth_testing_utils::generators::{self, random_block, random_header, BlockParams};
+    use reth_testing_utils::generators::{
+        self, random_block, random_block_range, random_header, BlockParams, BlockRangeParams,
+    };
     use std::{ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
 
@@ -707,6 +797,84 @@ mod tests {
         provider.block_hash(0).unwrap();
     }
 
+    #[test]
+    fn provider_factory_read_only_static() {
+        let (_static_dir, static_dir_path) = create_test_static_files_dir();
+        let mut rng = generators::rng();
+        let head = random_header(&mut rng, 0, None);
+
+        {
+            let static_file_provider =
+                StaticFileProvider::<EthPrimitives>::read_write(static_dir_path.clone()).unwrap();
+            let mut static_file_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            static_file_writer.append_header(head.header(), U256::ZERO, &head.hash()).unwrap();
+            static_file_writer.commit().unwrap();
+        }
+
+        let db_dir = tempfile::TempDir::new().expect(ERROR_TEMPDIR);
+        let factory = ProviderFactory::<MockNodeTypesWithDB<DatabaseEnv>>::read_only_static(
+            static_dir_path,
+            db_dir.path().to_path_buf(),
+            Arc::new(ChainSpecBuilder::mainnet().build()),
+        )
+        .unwrap();
+        let provider = factory.provider().unwrap();
+
+        // Headers are served from static files
+        assert_eq!(provider.header_by_number(0).unwrap(), Some(head.header().clone()));
+        assert_eq!(provider.block_hash(0).unwrap(), Some(head.hash()));
+
+        // Database-only data is missing, but doesn't error
+        assert_eq!(provider.block_body_indices(0).unwrap(), None);
+        assert_eq!(provider.block_number(head.hash()).unwrap(), None);
+    }
+
+    #[test]
+    fn table_sizes() {
+        use reth_db_api::{table::Table, transaction::DbTxMut};
+
+        let (_static_dir, static_dir_path) = create_test_static_files_dir();
+        let factory = ProviderFactory::<MockNodeTypesWithDB<DatabaseEnv>>::new_with_database_path(
+            tempfile::TempDir::new().expect(ERROR_TEMPDIR).into_path(),
+            Arc::new(ChainSpecBuilder::mainnet().build()),
+            DatabaseArguments::new(Default::default()),
+            StaticFileProvider::read_write(static_dir_path).unwrap(),
+        )
+        .unwrap();
+
+        let sizes = factory.table_sizes().unwrap();
+        assert_eq!(sizes.len(), Tables::ALL.len());
+        assert_eq!(sizes[tables::HeaderNumbers::NAME], 0);
+
+        let provider_rw = factory.provider_rw().unwrap();
+        for number in 0..1000 {
+            provider_rw.tx_ref().put::<tables::HeaderNumbers>(B256::random(), number).unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        let sizes = factory.table_sizes().unwrap();
+        assert!(sizes[tables::HeaderNumbers::NAME] > 0);
+        assert_eq!(sizes[tables::PlainAccountState::NAME], 0);
+    }
+
+    #[test]
+    fn find_block_gaps() {
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw().unwrap();
+        for number in [0, 1, 2, 5, 6, 9] {
+            provider_rw
+                .tx_ref()
+                .put::<tables::Headers>(number, Header { number, ..Default::default() })
+                .unwrap();
+        }
+
+        assert_eq!(provider_rw.find_block_gaps(0..=12).unwrap(), vec![3..=4, 7..=8, 10..=12]);
+        assert_eq!(provider_rw.find_block_gaps(4..6).unwrap(), vec![4..=4]);
+        assert!(provider_rw.find_block_gaps(0..=2).unwrap().is_empty());
+        assert!(provider_rw.find_block_gaps(..0).unwrap().is_empty());
+    }
+
     #[test]
     fn insert_block_with_prune_modes() {
         let factory = create_test_provider_factory();
@@ -741,6 +909,359 @@ mod tests {
         }
     }
 
+    #[test]
+    fn state_root_by_block() {
+        let factory = create_test_provider_factory();
+
+        // Nothing is in the hashed state yet
+        assert_eq!(
+            factory.latest().unwrap().compute_state_root().unwrap(),
+            alloy_consensus::constants::EMPTY_ROOT_HASH
+        );
+
+        let block = TEST_BLOCK.clone();
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.state_root_by_block(block.number).unwrap(), Some(block.state_root));
+        assert_eq!(provider.state_root_by_block(block.number + 1).unwrap(), None);
+    }
+
+    #[test]
+    fn headers_by_hashes() {
+        let factory = create_test_provider_factory();
+
+        let mut rng = generators::rng();
+        let blocks = (0..3)
+            .map(|number| {
+                random_block(
+                    &mut rng,
+                    number,
+                    BlockParams { tx_count: Some(0), ..Default::default() },
+                )
+            })
+            .collect::<Vec<_>>();
+        {
+            // Genesis header is in static files, the rest of the headers are in the database
+            let provider = factory.provider_rw().unwrap();
+            let static_file_provider = provider.static_file_provider();
+            let mut static_file_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            static_file_writer
+                .append_header(blocks[0].header.header(), U256::ZERO, &blocks[0].hash())
+                .unwrap();
+            static_file_writer.commit().unwrap();
+            drop(static_file_writer);
+
+            provider.tx_ref().put::<tables::HeaderNumbers>(blocks[0].hash(), 0).unwrap();
+            for block in &blocks[1..] {
+                provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        // Results follow the input order
+        let provider = factory.provider().unwrap();
+        let hashes = [blocks[2].hash(), B256::random(), blocks[0].hash(), blocks[1].hash()];
+        let expected = vec![
+            Some(blocks[2].header.header().clone()),
+            None,
+            Some(blocks[0].header.header().clone()),
+            Some(blocks[1].header.header().clone()),
+        ];
+        assert_eq!(provider.headers_by_hashes(&hashes).unwrap(), expected);
+        assert_eq!(factory.headers_by_hashes(&hashes).unwrap(), expected);
+        assert_eq!(provider.headers_by_hashes(&[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn header_by_timestamp() {
+        let factory = create_test_provider_factory();
+        assert_eq!(factory.provider().unwrap().header_by_timestamp(100).unwrap(), None);
+
+        // Blocks are 10 seconds apart, starting with genesis at 100
+        let headers = (0..10)
+            .map(|number| {
+                SealedHeader::seal(Header {
+                    number,
+                    timestamp: 100 + number * 10,
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<_>>();
+        {
+            let provider = factory.provider_rw().unwrap();
+            for header in &headers {
+                provider
+                    .tx_ref()
+                    .put::<tables::CanonicalHeaders>(header.number, header.hash())
+                    .unwrap();
+                provider
+                    .tx_ref()
+                    .put::<tables::Headers>(header.number, header.header().clone())
+                    .unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        // Exact match
+        assert_eq!(provider.header_by_timestamp(100).unwrap().as_ref(), Some(&headers[0]));
+        assert_eq!(provider.header_by_timestamp(170).unwrap().as_ref(), Some(&headers[7]));
+        // Between the blocks
+        assert_eq!(provider.header_by_timestamp(135).unwrap().as_ref(), Some(&headers[3]));
+        // After the latest block
+        assert_eq!(provider.header_by_timestamp(1_000).unwrap().as_ref(), Some(&headers[9]));
+        // Before genesis
+        assert_eq!(provider.header_by_timestamp(99).unwrap(), None);
+    }
+
+    #[test]
+    fn availability_floor() {
+        let factory = create_test_provider_factory();
+        assert_eq!(factory.availability_floor(PruneSegment::SenderRecovery).unwrap(), 0);
+
+        {
+            let provider = factory.provider_rw().unwrap();
+            for segment in [PruneSegment::SenderRecovery, PruneSegment::Headers] {
+                provider
+                    .save_prune_checkpoint(
+                        segment,
+                        PruneCheckpoint {
+                            block_number: Some(10),
+                            tx_number: None,
+                            prune_mode: PruneMode::Full,
+                        },
+                    )
+                    .unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        assert_eq!(factory.availability_floor(PruneSegment::SenderRecovery).unwrap(), 11);
+        // Headers are still available from static files
+        assert_eq!(factory.availability_floor(PruneSegment::Headers).unwrap(), 0);
+        assert_eq!(factory.availability_floor(PruneSegment::Receipts).unwrap(), 0);
+    }
+
+    #[test]
+    fn basic_accounts() {
+        let factory = create_test_provider_factory();
+        let account = |nonce| Account { nonce, ..Default::default() };
+
+        {
+            let provider = factory.provider_rw().unwrap();
+            for byte in [1, 3, 5] {
+                provider
+                    .tx_ref()
+                    .put::<tables::PlainAccountState>(
+                        Address::with_last_byte(byte),
+                        account(byte as u64),
+                    )
+                    .unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        // Results follow the input order, not the table order
+        let provider = factory.provider().unwrap();
+        let addresses = [5, 2, 1, 5, 3].map(Address::with_last_byte);
+        assert_eq!(
+            provider.basic_accounts(addresses).unwrap(),
+            vec![
+                (addresses[0], Some(account(5))),
+                (addresses[1], None),
+                (addresses[2], Some(account(1))),
+                (addresses[3], Some(account(5))),
+                (addresses[4], Some(account(3))),
+            ]
+        );
+        assert_eq!(provider.basic_accounts(Vec::new()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn verify_block_tx_root() {
+        let factory = create_test_provider_factory();
+
+        let block = TEST_BLOCK.clone();
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.verify_block_tx_root(block.number.into()).unwrap(), Some(true));
+        assert_eq!(provider.verify_block_tx_root(block.hash().into()).unwrap(), Some(true));
+        assert_eq!(provider.verify_block_tx_root((block.number + 1).into()).unwrap(), None);
+    }
+
+    #[test]
+    fn block_range_available() {
+        let factory = create_test_provider_factory();
+        assert_eq!(factory.block_range_available().unwrap(), None);
+
+        let block = TEST_BLOCK.clone();
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+
+        assert_eq!(factory.block_range_available().unwrap(), Some(block.number..=block.number));
+    }
+
+    #[test]
+    fn migration_guard() {
+        let factory = create_test_provider_factory().with_migration_guard();
+        let mut rng = generators::rng();
+        let head = random_header(&mut rng, 0, None);
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.header_by_number(0).unwrap(), None);
+
+        // Move the header to static files after the provider was opened
+        let static_file_provider = factory.static_file_provider();
+        let mut static_file_writer =
+            static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        static_file_writer.append_header(head.header(), U256::ZERO, &head.hash()).unwrap();
+        static_file_writer.commit().unwrap();
+        drop(static_file_writer);
+
+        // Reads across the changed boundary fail, while others still work
+        assert_matches!(
+            provider.header_by_number(0),
+            Err(ProviderError::StaleView(StaticFileSegment::Headers, 0))
+        );
+        assert_matches!(
+            provider.headers_range(0..=1),
+            Err(ProviderError::StaleView(StaticFileSegment::Headers, 0))
+        );
+        assert_eq!(provider.header_by_number(1).unwrap(), None);
+
+        // Reopening the provider records the new boundary
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.header_by_number(0).unwrap(), Some(head.header().clone()));
+    }
+
+    #[test]
+    fn export_and_import_blocks() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=2,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..3, ..Default::default() },
+        );
+        {
+            let provider = factory.provider_rw().unwrap();
+            for block in &blocks {
+                provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let mut exported = Vec::new();
+        assert_eq!(factory.export_blocks(1..=2, &mut exported), Ok(2));
+
+        let imported_factory = create_test_provider_factory();
+        {
+            let provider = imported_factory.provider_rw().unwrap();
+            // The first block must extend the canonical chain
+            assert_eq!(
+                provider.import_blocks(&mut exported.as_slice()),
+                Err(ProviderError::HeaderNotFound(0.into()))
+            );
+            provider.insert_block(blocks[0].clone().try_seal_with_senders().unwrap()).unwrap();
+            assert_eq!(provider.import_blocks(&mut exported.as_slice()), Ok(2));
+            provider.commit().unwrap();
+        }
+        for block in &blocks {
+            assert_eq!(
+                imported_factory.block(block.number.into()).unwrap(),
+                Some(block.clone().unseal())
+            );
+        }
+
+        // Re-exporting the imported blocks produces the same data
+        let mut reexported = Vec::new();
+        assert_eq!(imported_factory.export_blocks(1.., &mut reexported), Ok(2));
+        assert_eq!(reexported, exported);
+    }
+
+    #[test]
+    fn import_invalid_blocks() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=1,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(blocks[0].clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+        let export = |block: Block| {
+            let senders = block.senders().unwrap();
+            let mut exported = Vec::new();
+            write_block_with_senders(
+                &BlockWithSenders::new(block, senders).unwrap(),
+                &mut exported,
+            )
+            .unwrap();
+            exported
+        };
+
+        let provider = factory.provider_rw().unwrap();
+
+        // Block doesn't extend the canonical chain
+        let mut block = blocks[1].clone().unseal();
+        block.header.parent_hash = B256::random();
+        assert_eq!(
+            provider.import_blocks(&mut export(block.clone()).as_slice()),
+            Err(ProviderError::ParentHashMismatch(
+                1,
+                GotExpected { got: block.parent_hash, expected: blocks[0].hash() }.into()
+            ))
+        );
+
+        // Block body doesn't match the transactions root of its header
+        let mut block = blocks[1].clone().unseal();
+        block.body.transactions.pop();
+        assert_matches!(
+            provider.import_blocks(&mut export(block).as_slice()),
+            Err(ProviderError::BlockBodyValidation(error))
+                if matches!(*error, BodyValidationError::TransactionsRoot(_))
+        );
+
+        assert_eq!(provider.block(1.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn canonical_hash() {
+        let factory = create_test_provider_factory();
+
+        let block = TEST_BLOCK.clone();
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+
+        assert_eq!(factory.canonical_hash(block.number).unwrap(), Some(block.hash()));
+        assert_eq!(factory.canonical_hash(block.number + 1).unwrap(), None);
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.canonical_hash(block.number).unwrap(), Some(block.hash()));
+        assert_eq!(provider.canonical_hash(block.number + 1).unwrap(), None);
+    }
+
     #[test]
     fn take_block_transaction_range_recover_senders() {
         let factory = create_test_provider_factory();
@@ -790,6 +1311,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn transactions_by_tx_range_iter() {
+        let factory = create_test_provider_factory();
+
+        let mut rng = generators::rng();
+        let block =
+            random_block(&mut rng, 0, BlockParams { tx_count: Some(5), ..Default::default() });
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap()).unwrap();
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        for range in [0..5, 1..3, 3..10, 5..10] {
+            let transactions = provider
+                .transactions_by_tx_range_iter(range.clone())
+                .unwrap()
+                .collect::<ProviderResult<Vec<_>>>()
+                .unwrap();
+            assert_eq!(transactions, provider.transactions_by_tx_range(range.clone()).unwrap());
+            assert_eq!(
+                transactions,
+                block.body.transactions[range.start.min(5) as usize..range.end.min(5) as usize]
+                    .iter()
+                    .cloned()
+                    .map(Into::into)
+                    .collect::<Vec<TransactionSignedNoHash>>()
+            );
+        }
+    }
+
+    #[test]
+    fn receipts_by_tx_range() {
+        let factory = create_test_provider_factory();
+        let receipt = |tx_number| Receipt { cumulative_gas_used: tx_number, ..Default::default() };
+
+        // Receipts of the transactions below 3 were pruned
+        {
+            let provider = factory.provider_rw().unwrap();
+            for tx_number in 3..10 {
+                provider.tx_ref().put::<tables::Receipts>(tx_number, receipt(tx_number)).unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.receipts_by_tx_range(4..7).unwrap(),
+            (4..7).map(receipt).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            provider.receipts_by_tx_range(1..=4).unwrap(),
+            (3..=4).map(receipt).collect::<Vec<_>>()
+        );
+        assert_eq!(provider.receipts_by_tx_range(0..3).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn block_with_senders_range() {
+        let factory = create_test_provider_factory();
+
+        let mut rng = generators::rng();
+        let blocks = (0..2)
+            .map(|number| {
+                random_block(
+                    &mut rng,
+                    number,
+                    BlockParams { tx_count: Some(20), ..Default::default() },
+                )
+                .try_seal_with_senders()
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        {
+            let provider = factory.provider_rw().unwrap();
+            for block in &blocks {
+                provider.insert_block(block.clone()).unwrap();
+            }
+            // Senders of every other transaction are recovered from the transactions
+            for tx_number in (0..40).step_by(2) {
+                provider.tx_ref().delete::<tables::TransactionSenders>(tx_number, None).unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        let range = provider.block_with_senders_range(0..=1).unwrap();
+        assert_eq!(
+            range.iter().map(|block| &block.senders).collect::<Vec<_>>(),
+            blocks.iter().map(|block| &block.senders).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn header_sync_gap_lookup() {
         let factory = create_test_provider_factory();
@@ -30,7 +30,8 @@ use itertools::{izip, Itertools};
 use rayon::slice::ParallelSliceMut;
 use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_db::{
-    cursor::DbDupCursorRW, tables, BlockNumberList, PlainAccountState, PlainStorageState,
+    cursor::DbDupCursorRW, static_file::TransactionMask, tables, BlockNumberList,
+    PlainAccountState, PlainStorageState,
 };
 use reth_db_api::{
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO},
@@ -55,7 +56,10 @@ use reth_primitives::{
 use reth_primitives_traits::{BlockBody as _, FullNodePrimitives};
 use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
-use reth_storage_api::{StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider};
+use reth_storage_api::{
+    receipts_with_tx_hash_chunks, StateProvider, StorageChangeSetReader,
+    TryIntoHistoricalStateProvider,
+};
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
     prefix_set::{PrefixSet, PrefixSetMut, TriePrefixSets},
@@ -416,6 +420,52 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         )
     }
 
+    /// Returns an iterator over the transactions in the given range, reading them lazily instead of
+    /// collecting them into a vector like [`TransactionsProvider::transactions_by_tx_range`].
+    ///
+    /// The transactions available in static files are read first, and the rest is read from the
+    /// database with a single cursor that is kept open for the lifetime of the iterator.
+    pub fn transactions_by_tx_range_iter(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<TransactionSignedNoHash>> + '_> {
+        let (static_file_range, database_range) =
+            self.static_file_provider.split_range_with_static_file_or_database(
+                StaticFileSegment::Transactions,
+                to_range(range),
+            )?;
+
+        let static_file_transactions = static_file_range
+            .map(|range| {
+                self.static_file_provider.fetch_range_iter(
+                    StaticFileSegment::Transactions,
+                    range,
+                    |cursor, number| {
+                        cursor.get_one::<TransactionMask<TransactionSignedNoHash>>(number.into())
+                    },
+                )
+            })
+            .transpose()?;
+
+        let mut cursor = self.tx.cursor_read::<tables::Transactions>()?;
+        let mut start = Some(database_range.start);
+        let database_transactions = std::iter::from_fn(move || {
+            let entry = match start.take() {
+                Some(start) => cursor.seek(start),
+                None => cursor.next(),
+            };
+            match entry {
+                Ok(Some((number, transaction))) if number < database_range.end => {
+                    Some(Ok(transaction))
+                }
+                Ok(_) => None,
+                Err(err) => Some(Err(err.into())),
+            }
+        });
+
+        Ok(static_file_transactions.into_iter().flatten().chain(database_transactions))
+    }
+
     fn block_with_senders<H, HF, B, BF>(
         &self,
         id: BlockHashOrNumber,
@@ -596,19 +646,25 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
                         .walk_range(tx_range.clone())?
                         .collect::<Result<HashMap<_, _>, _>>()?;
 
-                let mut senders = Vec::with_capacity(body.len());
-                for (tx_num, tx) in tx_range.zip(body.iter()) {
-                    match known_senders.get(&tx_num) {
-                        None => {
-                            // recover the sender from the transaction if not found
-                            let sender = tx
-                                .recover_signer_unchecked()
-                                .ok_or(ProviderError::SenderRecoveryError)?;
-                            senders.push(sender);
-                        }
-                        Some(sender) => senders.push(*sender),
-                    }
-                }
+                // recover the senders not found from the transactions, in parallel if there are
+                // enough of them
+                let missing = tx_range
+                    .clone()
+                    .zip(body.iter())
+                    .filter(|(tx_num, _)| !known_senders.contains_key(tx_num))
+                    .map(|(_, tx)| tx)
+                    .collect::<Vec<_>>();
+                let missing_len = missing.len();
+                let mut recovered =
+                    TransactionSigned::recover_signers_unchecked(missing, missing_len)
+                        .ok_or(ProviderError::SenderRecoveryError)?
+                        .into_iter();
+
+                let senders = tx_range
+                    .take(body.len())
+                    .map(|tx_num| known_senders.get(&tx_num).copied().or_else(|| recovered.next()))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(ProviderError::SenderRecoveryError)?;
 
                 (body, senders)
             };
@@ -1144,11 +1200,19 @@ impl<TX: DbTx, N: NodeTypes> AccountExtReader for DatabaseProvider<TX, N> {
         &self,
         iter: impl IntoIterator<Item = Address>,
     ) -> ProviderResult<Vec<(Address, Option<Account>)>> {
+        let mut accounts =
+            iter.into_iter().map(|address| (address, None)).collect::<Vec<(Address, _)>>();
+
+        // Seek the addresses in the table order, so the cursor moves forward only
+        let mut order = (0..accounts.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&index| accounts[index].0);
+
         let mut plain_accounts = self.tx.cursor_read::<tables::PlainAccountState>()?;
-        Ok(iter
-            .into_iter()
-            .map(|address| plain_accounts.seek_exact(address).map(|a| (address, a.map(|(_, v)| v))))
-            .collect::<Result<Vec<_>, _>>()?)
+        for index in order {
+            let (address, account) = &mut accounts[index];
+            *account = plain_accounts.seek_exact(*address)?.map(|(_, account)| account);
+        }
+        Ok(accounts)
     }
 
     fn changed_accounts_and_blocks_with_range(
@@ -1257,6 +1321,42 @@ impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> HeaderProvi
         }
     }
 
+    fn headers_by_hashes(&self, hashes: &[BlockHash]) -> ProviderResult<Vec<Option<Header>>> {
+        // Resolve the hashes to block numbers, seeking them in the table order
+        let mut order = (0..hashes.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&index| hashes[index]);
+        let mut header_numbers = self.tx.cursor_read::<tables::HeaderNumbers>()?;
+        let mut blocks = Vec::with_capacity(hashes.len());
+        for index in order {
+            if let Some((_, number)) = header_numbers.seek_exact(hashes[index])? {
+                blocks.push((number, index));
+            }
+        }
+        blocks.sort_unstable();
+
+        let mut headers = vec![None; hashes.len()];
+        let (Some(&(first, _)), Some(&(last, _))) = (blocks.first(), blocks.last()) else {
+            return Ok(headers)
+        };
+
+        // Read the headers in the order of their block numbers, from static files if available
+        let (static_file_range, _) =
+            self.static_file_provider.split_range_with_static_file_or_database(
+                StaticFileSegment::Headers,
+                first..last + 1,
+            )?;
+        let mut header_cursor = self.tx.cursor_read::<tables::Headers>()?;
+        for (number, index) in blocks {
+            headers[index] =
+                if static_file_range.as_ref().is_some_and(|range| range.contains(&number)) {
+                    self.static_file_provider.header_by_number(number)?
+                } else {
+                    header_cursor.seek_exact(number)?.map(|(_, header)| header)
+                };
+        }
+        Ok(headers)
+    }
+
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
         self.static_file_provider.get_with_static_file_or_database(
             StaticFileSegment::Headers,
@@ -1317,6 +1417,10 @@ impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> HeaderProvi
         )
     }
 
+    fn canonical_hash(&self, number: BlockNumber) -> ProviderResult<Option<BlockHash>> {
+        self.block_hash(number)
+    }
+
     fn sealed_headers_while(
         &self,
         range: impl RangeBounds<BlockNumber>,
@@ -1479,6 +1583,29 @@ impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> BlockReader
         Ok(self.tx.get::<tables::BlockBodyIndices>(num)?)
     }
 
+    /// Returns the range of blocks available in static files and the database.
+    ///
+    /// Static files always start at genesis, and the database continues where they end. Pruning
+    /// only deletes the transactions from the database after they were moved to static files, so
+    /// the range isn't affected by it.
+    fn block_range_available(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        let static_file_highest =
+            self.static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers);
+        let mut cursor = self.tx.cursor_read::<tables::CanonicalHeaders>()?;
+        let database_range =
+            cursor.first()?.map(|(first, _)| first).zip(cursor.last()?.map(|(last, _)| last));
+
+        let range = match (static_file_highest, database_range) {
+            (Some(static_file_highest), Some((_, database_last))) => {
+                Some(0..=static_file_highest.max(database_last))
+            }
+            (Some(static_file_highest), None) => Some(0..=static_file_highest),
+            (None, Some((database_first, database_last))) => Some(database_first..=database_last),
+            (None, None) => None,
+        };
+        Ok(range)
+    }
+
     /// Returns the block with senders with matching number or hash from database.
     ///
     /// **NOTE: The transactions have invalid hashes, since they would need to be calculated on the
@@ -1857,6 +1984,13 @@ impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> ReceiptProv
             |_| true,
         )
     }
+
+    fn receipts_with_tx_hash_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(B256, Receipt)>>> {
+        Ok(receipts_with_tx_hash_chunks(self, to_range(range)))
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> WithdrawalsProvider
@@ -13,6 +13,7 @@ use alloy_eips::{
     BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag,
 };
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
+use alloy_rpc_types_eth::{Filter, Log};
 use reth_blockchain_tree_api::{
     error::{CanonicalError, InsertBlockError},
     BlockValidationKind, BlockchainTreeEngine, BlockchainTreeViewer, CanonicalOutcome,
@@ -29,6 +30,7 @@ use reth_primitives::{
 };
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
+use reth_storage_api::logs_by_header_blooms;
 use reth_storage_errors::provider::ProviderResult;
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
@@ -242,6 +244,10 @@ impl<N: ProviderNodeTypes> HeaderProvider for BlockchainProvider<N> {
         self.database.header(block_hash)
     }
 
+    fn headers_by_hashes(&self, hashes: &[BlockHash]) -> ProviderResult<Vec<Option<Header>>> {
+        self.database.headers_by_hashes(hashes)
+    }
+
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
         self.database.header_by_number(num)
     }
@@ -498,6 +504,17 @@ impl<N: ProviderNodeTypes> ReceiptProvider for BlockchainProvider<N> {
     ) -> ProviderResult<Vec<Receipt>> {
         self.database.receipts_by_tx_range(range)
     }
+
+    fn receipts_with_tx_hash_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(B256, Receipt)>>> {
+        self.database.receipts_with_tx_hash_range(range)
+    }
+
+    fn logs_by_filter(&self, filter: &Filter) -> ProviderResult<Vec<Log>> {
+        logs_by_header_blooms(self, filter)
+    }
 }
 
 impl<N: ProviderNodeTypes> ReceiptProviderIdExt for BlockchainProvider<N> {
@@ -499,9 +499,10 @@ mod tests {
     use crate::{
         providers::state::historical::{HistoryInfo, LowestAvailableBlocks},
         test_utils::create_test_provider_factory,
-        AccountReader, HistoricalStateProvider, HistoricalStateProviderRef, StateProvider,
+        AccountReader, HistoricalStateProvider, HistoricalStateProviderRef, LatestStateProvider,
+        StateProvider,
     };
-    use alloy_primitives::{address, b256, Address, B256, U256};
+    use alloy_primitives::{address, b256, keccak256, Address, B256, U256};
     use reth_db::{tables, BlockNumberList};
     use reth_db_api::{
         models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
@@ -628,6 +629,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn history_provider_account_proof() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let acc_at1 = Account { nonce: 1, balance: U256::from(10), bytecode_hash: None };
+        let acc_plain = Account { nonce: 2, balance: U256::from(5), bytecode_hash: None };
+        let storage = StorageEntry { key: STORAGE, value: U256::from(7) };
+
+        // The account changed in block 1, its storage didn't change
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: u64::MAX },
+            BlockNumberList::new([1]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            1,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at1) },
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(ADDRESS, acc_plain).unwrap();
+        tx.put::<tables::HashedAccounts>(keccak256(ADDRESS), acc_plain).unwrap();
+        tx.put::<tables::PlainStorageState>(ADDRESS, storage).unwrap();
+        tx.put::<tables::HashedStorages>(
+            keccak256(ADDRESS),
+            StorageEntry { key: keccak256(STORAGE), ..storage },
+        )
+        .unwrap();
+        tx.commit().unwrap();
+
+        let latest = LatestStateProvider::new(factory.provider().unwrap());
+        let latest_root = latest.compute_state_root().unwrap();
+        let proof = latest.account_proof(ADDRESS, &[STORAGE]).unwrap();
+        assert_eq!(proof.info, Some(acc_plain));
+        assert_eq!(proof.storage_proofs[0].value, storage.value);
+        proof.verify(latest_root).unwrap();
+
+        let historical = HistoricalStateProvider::new(factory.provider().unwrap(), 1);
+        let historical_root = historical.compute_state_root().unwrap();
+        assert_ne!(historical_root, latest_root);
+        let proof = historical.account_proof(ADDRESS, &[STORAGE]).unwrap();
+        assert_eq!(proof.info, Some(acc_at1));
+        assert_eq!(proof.storage_proofs[0].value, storage.value);
+        proof.verify(historical_root).unwrap();
+        assert!(proof.verify(latest_root).is_err());
+    }
+
     #[test]
     fn history_provider_get_storage() {
         let factory = create_test_provider_factory();
@@ -7,7 +7,10 @@ use alloy_primitives::{
     Address, BlockNumber, Bytes, StorageKey, StorageValue, B256,
 };
 use reth_db::tables;
-use reth_db_api::{cursor::DbDupCursorRO, transaction::DbTx};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    transaction::DbTx,
+};
 use reth_primitives::{Account, Bytecode};
 use reth_storage_api::{DBProvider, StateProofProvider, StorageRootProvider};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
@@ -160,6 +163,34 @@ impl<Provider: DBProvider + BlockHashReader> StateProvider
     fn bytecode_by_hash(&self, code_hash: B256) -> ProviderResult<Option<Bytecode>> {
         self.tx().get::<tables::Bytecodes>(code_hash).map_err(Into::into)
     }
+
+    /// Scan all plain accounts for the ones with the given bytecode hash.
+    fn accounts_by_code_hash(&self, code_hash: B256, limit: usize) -> ProviderResult<Vec<Address>> {
+        let mut accounts = Vec::new();
+        if limit == 0 {
+            return Ok(accounts)
+        }
+
+        for entry in self.tx().cursor_read::<tables::PlainAccountState>()?.walk(None)? {
+            let (address, account) = entry?;
+            if account.bytecode_hash == Some(code_hash) {
+                accounts.push(address);
+                if accounts.len() >= limit {
+                    break
+                }
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Look up the hashed account. Address preimages aren't stored, so the address is never
+    /// resolved.
+    fn account_by_hashed_address(
+        &self,
+        hashed_address: B256,
+    ) -> ProviderResult<Option<(Option<Address>, Account)>> {
+        Ok(self.tx().get::<tables::HashedAccounts>(hashed_address)?.map(|account| (None, account)))
+    }
 }
 
 /// State provider for the latest state.
@@ -191,4 +222,61 @@ mod tests {
     const fn assert_latest_state_provider<T: DBProvider + BlockHashReader>() {
         assert_state_provider::<LatestStateProvider<T>>();
     }
+
+    #[test]
+    fn accounts_by_code_hash() {
+        use crate::{test_utils::create_test_provider_factory, DatabaseProviderFactory};
+        use reth_db_api::transaction::DbTxMut;
+
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.database_provider_rw().unwrap();
+
+        let code_hash = B256::random();
+        let contracts = [Address::with_last_byte(1), Address::with_last_byte(3)];
+        let accounts = [
+            (contracts[0], Account { bytecode_hash: Some(code_hash), ..Default::default() }),
+            (Address::with_last_byte(2), Account { bytecode_hash: None, ..Default::default() }),
+            (contracts[1], Account { bytecode_hash: Some(code_hash), ..Default::default() }),
+            (
+                Address::with_last_byte(4),
+                Account { bytecode_hash: Some(B256::random()), ..Default::default() },
+            ),
+        ];
+        for (address, account) in accounts {
+            provider_rw.tx_ref().put::<tables::PlainAccountState>(address, account).unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        let provider = factory.database_provider_ro().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(state.accounts_by_code_hash(code_hash, 10).unwrap(), contracts);
+        assert_eq!(state.accounts_by_code_hash(code_hash, 1).unwrap(), contracts[..1]);
+        assert!(state.accounts_by_code_hash(code_hash, 0).unwrap().is_empty());
+        assert!(state.accounts_by_code_hash(B256::random(), 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn account_by_hashed_address() {
+        use crate::{test_utils::create_test_provider_factory, DatabaseProviderFactory};
+        use alloy_primitives::{keccak256, U256};
+        use reth_db_api::transaction::DbTxMut;
+
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.database_provider_rw().unwrap();
+
+        let address = Address::with_last_byte(1);
+        let account = Account { nonce: 1, balance: U256::from(10), bytecode_hash: None };
+        provider_rw.tx_ref().put::<tables::PlainAccountState>(address, account).unwrap();
+        provider_rw.tx_ref().put::<tables::HashedAccounts>(keccak256(address), account).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.database_provider_ro().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(state.hashed_address(address), keccak256(address));
+        assert_eq!(
+            state.account_by_hashed_address(keccak256(address)).unwrap(),
+            Some((None, account))
+        );
+        assert_eq!(state.account_by_hashed_address(B256::random()).unwrap(), None);
+    }
 }
@@ -40,6 +40,8 @@ macro_rules! delegate_provider_impls {
             StateProvider $(where [$($generics)*])? {
                 fn storage(&self, account: alloy_primitives::Address, storage_key: alloy_primitives::StorageKey) -> reth_storage_errors::provider::ProviderResult<Option<alloy_primitives::StorageValue>>;
                 fn bytecode_by_hash(&self, code_hash: alloy_primitives::B256) -> reth_storage_errors::provider::ProviderResult<Option<reth_primitives::Bytecode>>;
+                fn accounts_by_code_hash(&self, code_hash: alloy_primitives::B256, limit: usize) -> reth_storage_errors::provider::ProviderResult<Vec<alloy_primitives::Address>>;
+                fn account_by_hashed_address(&self, hashed_address: alloy_primitives::B256) -> reth_storage_errors::provider::ProviderResult<Option<(Option<alloy_primitives::Address>, reth_primitives::Account)>>;
             }
             StateRootProvider $(where [$($generics)*])? {
                 fn state_root(&self, state: reth_trie::HashedPostState) -> reth_storage_errors::provider::ProviderResult<alloy_primitives::B256>;
@@ -81,18 +81,40 @@ impl StaticFileAccess {
 
 /// [`StaticFileProvider`] manages all existing [`StaticFileJarProvider`].
 #[derive(Debug)]
-pub struct StaticFileProvider<N>(pub(crate) Arc<StaticFileProviderInner<N>>);
+pub struct StaticFileProvider<N>(
+    pub(crate) Arc<StaticFileProviderInner<N>>,
+    /// Static file boundaries recorded by [`StaticFileProvider::with_migration_guard`].
+    Option<StaticFileBoundaries>,
+);
 
 impl<N> Clone for StaticFileProvider<N> {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self(self.0.clone(), self.1)
+    }
+}
+
+/// Highest block or transaction number of each segment in static files.
+#[derive(Debug, Clone, Copy)]
+struct StaticFileBoundaries {
+    headers: Option<u64>,
+    transactions: Option<u64>,
+    receipts: Option<u64>,
+}
+
+impl StaticFileBoundaries {
+    const fn get(&self, segment: StaticFileSegment) -> Option<u64> {
+        match segment {
+            StaticFileSegment::Headers => self.headers,
+            StaticFileSegment::Transactions => self.transactions,
+            StaticFileSegment::Receipts => self.receipts,
+        }
     }
 }
 
 impl<N: NodePrimitives> StaticFileProvider<N> {
     /// Creates a new [`StaticFileProvider`].
     fn new(path: impl AsRef<Path>, access: StaticFileAccess) -> ProviderResult<Self> {
-        let provider = Self(Arc::new(StaticFileProviderInner::new(path, access)?));
+        let provider = Self(Arc::new(StaticFileProviderInner::new(path, access)?), None);
         provider.initialize_index()?;
         Ok(provider)
     }
@@ -277,7 +299,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         let mut provider =
             Arc::try_unwrap(self.0).expect("should be called when initializing only");
         provider.blocks_per_file = blocks_per_file;
-        Self(Arc::new(provider))
+        Self(Arc::new(provider), self.1)
     }
 
     /// Enables metrics on the [`StaticFileProvider`].
@@ -285,7 +307,55 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         let mut provider =
             Arc::try_unwrap(self.0).expect("should be called when initializing only");
         provider.metrics = Some(Arc::new(StaticFileProviderMetrics::default()));
-        Self(Arc::new(provider))
+        Self(Arc::new(provider), self.1)
+    }
+
+    /// Returns a copy of the provider that records the current static file boundaries of all
+    /// segments.
+    ///
+    /// Reads through the returned provider fail with [`ProviderError::StaleView`] if they would
+    /// touch data that was moved between the database and static files since this call, e.g. by
+    /// the static file producer or an unwind. Such a view can't be refreshed in place: recover by
+    /// dropping it and opening a new provider.
+    pub fn with_migration_guard(&self) -> Self {
+        let boundaries = StaticFileBoundaries {
+            headers: self.upper_bound(StaticFileSegment::Headers),
+            transactions: self.upper_bound(StaticFileSegment::Transactions),
+            receipts: self.upper_bound(StaticFileSegment::Receipts),
+        };
+        Self(self.0.clone(), Some(boundaries))
+    }
+
+    /// Returns the highest block (for headers) or transaction (for transactions and receipts)
+    /// number of the segment in static files.
+    fn upper_bound(&self, segment: StaticFileSegment) -> Option<u64> {
+        match segment {
+            StaticFileSegment::Headers => self.get_highest_static_file_block(segment),
+            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+                self.get_highest_static_file_tx(segment)
+            }
+        }
+    }
+
+    /// Returns the current static file upper bound of the segment, or
+    /// [`ProviderError::StaleView`] if the provider has a migration guard and the boundary moved
+    /// across the given `range` since the guard was recorded.
+    fn guarded_upper_bound(
+        &self,
+        segment: StaticFileSegment,
+        range: Range<u64>,
+    ) -> ProviderResult<Option<u64>> {
+        let current = self.upper_bound(segment);
+        if let Some(recorded) = self.1.map(|boundaries| boundaries.get(segment)) {
+            // Numbers in `low..=high` changed sides between the database and static files.
+            let low = recorded.min(current).map_or(0, |bound| bound + 1);
+            if let Some(high) = recorded.max(current).filter(|&high| high >= low) {
+                if range.start <= high && range.end > low {
+                    return Err(ProviderError::StaleView(segment, range.start.max(low)))
+                }
+            }
+        }
+        Ok(current)
     }
 
     /// Reports metrics for the static files.
@@ -1099,12 +1169,8 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         FD: Fn() -> ProviderResult<Option<T>>,
     {
         // If there is, check the maximum block or transaction number of the segment.
-        let static_file_upper_bound = match segment {
-            StaticFileSegment::Headers => self.get_highest_static_file_block(segment),
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
-                self.get_highest_static_file_tx(segment)
-            }
-        };
+        let static_file_upper_bound =
+            self.guarded_upper_bound(segment, number..number.saturating_add(1))?;
 
         if static_file_upper_bound
             .is_some_and(|static_file_upper_bound| static_file_upper_bound >= number)
@@ -1128,7 +1194,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
     pub fn get_range_with_static_file_or_database<T, P, FS, FD>(
         &self,
         segment: StaticFileSegment,
-        mut block_or_tx_range: Range<u64>,
+        block_or_tx_range: Range<u64>,
         fetch_from_static_file: FS,
         mut fetch_from_database: FD,
         mut predicate: P,
@@ -1140,29 +1206,40 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
     {
         let mut data = Vec::new();
 
+        let (static_file_range, database_range) =
+            self.split_range_with_static_file_or_database(segment, block_or_tx_range)?;
+        if let Some(static_file_range) = static_file_range {
+            data.extend(fetch_from_static_file(self, static_file_range, &mut predicate)?);
+        }
+
+        if !database_range.is_empty() {
+            data.extend(fetch_from_database(database_range, predicate)?)
+        }
+
+        Ok(data)
+    }
+
+    /// Splits the range into the part available in static files, if any, and the remaining part
+    /// to be read from the database, as done by [`Self::get_range_with_static_file_or_database`].
+    pub fn split_range_with_static_file_or_database(
+        &self,
+        segment: StaticFileSegment,
+        mut block_or_tx_range: Range<u64>,
+    ) -> ProviderResult<(Option<Range<u64>>, Range<u64>)> {
+        let mut static_file_range = None;
+
         // If there is, check the maximum block or transaction number of the segment.
-        if let Some(static_file_upper_bound) = match segment {
-            StaticFileSegment::Headers => self.get_highest_static_file_block(segment),
-            StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
-                self.get_highest_static_file_tx(segment)
-            }
-        } {
+        if let Some(static_file_upper_bound) =
+            self.guarded_upper_bound(segment, block_or_tx_range.clone())?
+        {
             if block_or_tx_range.start <= static_file_upper_bound {
                 let end = block_or_tx_range.end.min(static_file_upper_bound + 1);
-                data.extend(fetch_from_static_file(
-                    self,
-                    block_or_tx_range.start..end,
-                    &mut predicate,
-                )?);
+                static_file_range = Some(block_or_tx_range.start..end);
                 block_or_tx_range.start = end;
             }
         }
 
-        if block_or_tx_range.end > block_or_tx_range.start {
-            data.extend(fetch_from_database(block_or_tx_range, predicate)?)
-        }
-
-        Ok(data)
+        Ok((static_file_range, block_or_tx_range))
     }
 
     /// Returns `static_files` directory
@@ -776,7 +776,10 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
     fn upgrade_provider_to_strong_reference(
         provider: &Weak<StaticFileProviderInner<N>>,
     ) -> StaticFileProvider<N> {
-        provider.upgrade().map(StaticFileProvider).expect("StaticFileProvider is dropped")
+        provider
+            .upgrade()
+            .map(|inner| StaticFileProvider(inner, None))
+            .expect("StaticFileProvider is dropped")
     }
 
     /// Helper function to access [`SegmentHeader`].
@@ -1,10 +1,12 @@
 use alloy_primitives::BlockNumber;
 use reth_db_api::models::StoredBlockBodyIndices;
 use reth_execution_types::{Chain, ExecutionOutcome};
-use reth_primitives::SealedBlockWithSenders;
-use reth_storage_errors::provider::ProviderResult;
+use reth_primitives::{GotExpected, SealedBlockWithSenders};
+use reth_primitives_traits::validate_body_roots;
+use reth_storage_api::{read_block_with_senders, BlockHashReader};
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
 use reth_trie::{updates::TrieUpdates, HashedPostStateSorted};
-use std::ops::RangeInclusive;
+use std::{io::Read, ops::RangeInclusive};
 
 /// BlockExecution Writer
 #[auto_impl::auto_impl(&, Arc, Box)]
@@ -43,6 +45,48 @@ pub trait BlockWriter: Send + Sync {
     fn insert_block(&self, block: SealedBlockWithSenders)
         -> ProviderResult<StoredBlockBodyIndices>;
 
+    /// Inserts the blocks exported with
+    /// [`BlockReader::export_blocks`](reth_storage_api::BlockReader::export_blocks) from `reader`
+    /// until its end, and returns the number of inserted blocks.
+    ///
+    /// The first block must extend the canonical chain and each following block must extend the
+    /// previous one. The roots of each block header are checked against its body before the block
+    /// is inserted with [`Self::insert_block`].
+    #[auto_impl(keep_default_for(&, Arc, Box))]
+    fn import_blocks(&self, reader: &mut impl Read) -> ProviderResult<u64>
+    where
+        Self: BlockHashReader,
+    {
+        let mut parent_hash = None;
+        let mut inserted = 0;
+        while let Some(block) = read_block_with_senders(reader)? {
+            let block = block.seal_slow();
+
+            let expected_parent_hash = match parent_hash {
+                Some(parent_hash) => Some(parent_hash),
+                None if block.number == 0 => None,
+                None => Some(
+                    self.block_hash(block.number - 1)?
+                        .ok_or_else(|| ProviderError::HeaderNotFound((block.number - 1).into()))?,
+                ),
+            };
+            if let Some(expected) = expected_parent_hash {
+                if block.parent_hash != expected {
+                    return Err(ProviderError::ParentHashMismatch(
+                        block.number,
+                        GotExpected { got: block.parent_hash, expected }.into(),
+                    ))
+                }
+            }
+            validate_body_roots(&block.body, block.header.header())?;
+
+            parent_hash = Some(block.hash());
+            self.insert_block(block)?;
+            inserted += 1;
+        }
+        Ok(inserted)
+    }
+
     /// Appends a batch of block bodies extending the canonical chain. This is invoked during
     /// `Bodies` stage and does not write to `TransactionHashNumbers` and `TransactionSenders`
     /// tables which are populated on later stages.
@@ -29,7 +29,8 @@ pub trait AccountExtReader: Send + Sync {
     /// Get basic account information for multiple accounts. A more efficient version than calling
     /// [`AccountReader::basic_account`] repeatedly.
     ///
-    /// Returns `None` if the account doesn't exist.
+    /// Returns the accounts in the order of the input addresses, with `None` if the account doesn't
+    /// exist.
     fn basic_accounts(
         &self,
         _iter: impl IntoIterator<Item = Address>,
@@ -1,16 +1,51 @@
 use crate::{
-    BlockNumReader, HeaderProvider, ReceiptProvider, ReceiptProviderIdExt, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    BlockNumReader, HeaderProvider, ReceiptMeta, ReceiptProvider, ReceiptProviderIdExt,
+    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_consensus::Header;
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
-use alloy_primitives::{BlockNumber, B256};
+use alloy_eips::{eip4895::Withdrawals, BlockHashOrNumber, BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, BlockNumber, B256};
+use alloy_rlp::{Decodable, Encodable};
 use reth_db_models::StoredBlockBodyIndices;
 use reth_primitives::{
     Block, BlockWithSenders, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader,
+    TransactionSigned,
 };
-use reth_storage_errors::provider::ProviderResult;
-use std::ops::RangeInclusive;
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use std::{
+    io::{ErrorKind, Read, Write},
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+/// Number of blocks read at once by [`BlockReader::export_blocks`].
+const EXPORT_BLOCKS_CHUNK_SIZE: u64 = 100;
+
+/// Number of headers read at once by [`BlockReader::find_block_gaps`].
+const FIND_BLOCK_GAPS_CHUNK_SIZE: u64 = 1_000;
+
+/// Resolves the bounds of a block number `range` to inclusive start and end blocks, ending an
+/// unbounded range at the block returned by `last_block`.
+///
+/// Returns `None` if the range ends before block `0`.
+fn block_range_bounds(
+    range: impl RangeBounds<BlockNumber>,
+    last_block: impl FnOnce() -> ProviderResult<BlockNumber>,
+) -> ProviderResult<Option<(BlockNumber, BlockNumber)>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => match end.checked_sub(1) {
+            Some(end) => end,
+            None => return Ok(None),
+        },
+        Bound::Unbounded => last_block()?,
+    };
+    Ok(Some((start, end)))
+}
 
 /// A helper enum that represents the origin of the requested block.
 ///
@@ -43,6 +78,25 @@ impl BlockSource {
     }
 }
 
+/// Lightweight summary of a block, see [`BlockReader::block_summaries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSummary {
+    /// Block number.
+    pub number: BlockNumber,
+    /// Block hash.
+    pub hash: B256,
+    /// Block timestamp.
+    pub timestamp: u64,
+    /// Total gas used by the block's transactions.
+    pub gas_used: u64,
+    /// Gas limit of the block.
+    pub gas_limit: u64,
+    /// Number of transactions in the block.
+    pub tx_count: u64,
+    /// Base fee per gas of the block, if it's post-London.
+    pub base_fee: Option<u64>,
+}
+
 /// Api trait for fetching `Block` related data.
 ///
 /// If not requested otherwise, implementers of this trait should prioritize fetching blocks from
@@ -89,6 +143,22 @@ pub trait BlockReader:
     /// Returns `None` if block is not found.
     fn ommers(&self, id: BlockHashOrNumber) -> ProviderResult<Option<Vec<Header>>>;
 
+    /// Returns the transactions, ommers and withdrawals of the block with the given number, which
+    /// together make up the block body.
+    ///
+    /// Returns `None` if the block is not found, or its transactions are not available.
+    #[allow(clippy::type_complexity)]
+    fn block_body_parts(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<(Vec<TransactionSigned>, Vec<Header>, Option<Withdrawals>)>> {
+        let Some(header) = self.header_by_number(block)? else { return Ok(None) };
+        let Some(transactions) = self.transactions_by_block(block.into())? else { return Ok(None) };
+        let ommers = self.ommers(block.into())?.unwrap_or_default();
+        let withdrawals = self.withdrawals_by_block(block.into(), header.timestamp)?;
+        Ok(Some((transactions, ommers, withdrawals)))
+    }
+
     /// Returns the block with matching hash from the database.
     ///
     /// Returns `None` if block is not found.
@@ -108,6 +178,19 @@ pub trait BlockReader:
     /// Returns `None` if block is not found.
     fn block_body_indices(&self, num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>>;
 
+    /// Returns the range of blocks with headers and bodies available, from the earliest to the
+    /// latest block.
+    ///
+    /// Returns `None` if there are no blocks, e.g. on an empty database. By default, block headers
+    /// and bodies are assumed to be kept since genesis, so the range starts at genesis if its
+    /// header is available.
+    fn block_range_available(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        if self.header_by_number(0)?.is_none() {
+            return Ok(None)
+        }
+        Ok(Some(0..=self.last_block_number()?))
+    }
+
     /// Returns the block with senders with matching number or hash from database.
     ///
     /// Returns the block's transactions in the requested variant.
@@ -135,8 +218,126 @@ pub trait BlockReader:
     /// Note: returns only available blocks
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Block>>;
 
+    /// Returns the latest `n` blocks ending at the tip of the chain, newest first.
+    ///
+    /// `n` is clamped to the number of available blocks, see [`Self::block_range_available`]. The
+    /// blocks are read with a single [`Self::block_range`] query.
+    fn latest_blocks(&self, n: usize) -> ProviderResult<Vec<SealedBlock>> {
+        let Some(available) = self.block_range_available()? else { return Ok(Vec::new()) };
+        if n == 0 {
+            return Ok(Vec::new())
+        }
+
+        let tip = *available.end();
+        let start = tip.saturating_sub(n as u64 - 1).max(*available.start());
+        let mut blocks =
+            self.block_range(start..=tip)?.into_iter().map(Block::seal_slow).collect::<Vec<_>>();
+        blocks.reverse();
+        Ok(blocks)
+    }
+
+    /// Writes the blocks in the given range, along with the senders of their transactions, to
+    /// `out`, and returns the number of written blocks.
+    ///
+    /// Each block is written as an entry of the interchange format, see
+    /// [`write_block_with_senders`], in ascending block number order. Only available blocks are
+    /// written, and an unbounded range ends at the last block. The blocks can be imported into
+    /// another node by reading the entries with [`read_block_with_senders`].
+    fn export_blocks(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+        out: &mut impl Write,
+    ) -> ProviderResult<u64> {
+        let Some((start, end)) = block_range_bounds(range, || self.last_block_number())? else {
+            return Ok(0)
+        };
+
+        let mut written = 0;
+        let mut chunk_start = start;
+        while chunk_start <= end {
+            let chunk_end = end.min(chunk_start.saturating_add(EXPORT_BLOCKS_CHUNK_SIZE - 1));
+            for block in self.block_with_senders_range(chunk_start..=chunk_end)? {
+                write_block_with_senders(&block, out)?;
+                written += 1;
+            }
+            let Some(next) = chunk_end.checked_add(1) else { break };
+            chunk_start = next;
+        }
+        out.flush().map_err(|err| ProviderError::Io(err.to_string()))?;
+        Ok(written)
+    }
+
+    /// Returns the sub-ranges of the given range with no stored headers, in ascending order. An
+    /// unbounded range ends at the last block.
+    ///
+    /// Headers are read in chunks with [`HeaderProvider::headers_range`], so the gaps are found
+    /// with range reads instead of per-block lookups. Returns an empty [`Vec`] if all headers in
+    /// the range are stored.
+    fn find_block_gaps(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<RangeInclusive<BlockNumber>>> {
+        let Some((start, end)) = block_range_bounds(range, || self.last_block_number())? else {
+            return Ok(Vec::new())
+        };
+
+        let mut gaps = Vec::new();
+        // Lowest block number in the range that wasn't seen yet, `None` after the last one
+        let mut next = Some(start);
+        let mut chunk_start = start;
+        while chunk_start <= end {
+            let chunk_end = end.min(chunk_start.saturating_add(FIND_BLOCK_GAPS_CHUNK_SIZE - 1));
+            for header in self.headers_range(chunk_start..=chunk_end)? {
+                if let Some(expected) = next.filter(|&expected| header.number > expected) {
+                    gaps.push(expected..=header.number - 1);
+                }
+                next = header.number.checked_add(1);
+            }
+            let Some(next_chunk) = chunk_end.checked_add(1) else { break };
+            chunk_start = next_chunk;
+        }
+        if let Some(expected) = next.filter(|&expected| expected <= end) {
+            gaps.push(expected..=end);
+        }
+        Ok(gaps)
+    }
+
+    /// Returns the [`BlockSummary`] of every block in the range, in ascending block number order.
+    /// An unbounded range ends at the last block.
+    ///
+    /// Summaries are assembled from the sealed headers and the body indices, so no transactions
+    /// or receipts are read.
+    fn block_summaries(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockSummary>> {
+        let Some((start, end)) = block_range_bounds(range, || self.last_block_number())? else {
+            return Ok(Vec::new())
+        };
+
+        self.sealed_headers_range(start..=end)?
+            .into_iter()
+            .map(|header| {
+                let tx_count =
+                    self.block_body_indices(header.number)?.map_or(0, |indices| indices.tx_count());
+                Ok(BlockSummary {
+                    number: header.number,
+                    hash: header.hash(),
+                    timestamp: header.timestamp,
+                    gas_used: header.gas_used,
+                    gas_limit: header.gas_limit,
+                    tx_count,
+                    base_fee: header.base_fee_per_gas,
+                })
+            })
+            .collect()
+    }
+
     /// Returns a range of blocks from the database, along with the senders of each
     /// transaction in the blocks.
+    ///
+    /// Senders missing from the database, e.g. pruned ones, are recovered from the transactions of
+    /// each block, in parallel if there are enough of them.
     fn block_with_senders_range(
         &self,
         range: RangeInclusive<BlockNumber>,
@@ -150,6 +351,103 @@ pub trait BlockReader:
     ) -> ProviderResult<Vec<SealedBlockWithSenders>>;
 }
 
+/// Writes the block with senders as an entry of the block interchange format: the length of the
+/// entry as a big-endian `u32`, followed by the RLP list of the block and the list of senders.
+pub fn write_block_with_senders(
+    block: &BlockWithSenders,
+    out: &mut impl Write,
+) -> ProviderResult<()> {
+    let header = alloy_rlp::Header {
+        list: true,
+        payload_length: block.block.length() + block.senders.length(),
+    };
+    let mut entry = Vec::with_capacity(header.length_with_payload());
+    header.encode(&mut entry);
+    block.block.encode(&mut entry);
+    block.senders.encode(&mut entry);
+
+    let length = u32::try_from(entry.len()).map_err(|_| {
+        ProviderError::Io(format!("block entry of {} bytes is too large", entry.len()))
+    })?;
+    out.write_all(&length.to_be_bytes())
+        .and_then(|()| out.write_all(&entry))
+        .map_err(|err| ProviderError::Io(err.to_string()))
+}
+
+/// Reads the next block with senders written by [`write_block_with_senders`].
+///
+/// Returns `None` if the reader is at its end. Fails if the reader ends after the length of an
+/// entry, but before the end of its data.
+pub fn read_block_with_senders(reader: &mut impl Read) -> ProviderResult<Option<BlockWithSenders>> {
+    let mut length = [0; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(ProviderError::Io(err.to_string())),
+    }
+
+    let mut entry = vec![0; u32::from_be_bytes(length) as usize];
+    reader.read_exact(&mut entry).map_err(|err| ProviderError::Io(err.to_string()))?;
+
+    let mut payload = alloy_rlp::Header::decode_bytes(&mut entry.as_slice(), true)?;
+    let block = Block::decode(&mut payload)?;
+    let senders = Vec::<Address>::decode(&mut payload)?;
+    BlockWithSenders::new(block, senders).map(Some).ok_or(ProviderError::SenderRecoveryError)
+}
+
+/// Helper methods on top of [`BlockReader`] that are implemented for every block reader.
+pub trait BlockReaderExt: BlockReader {
+    /// Recomputes the transactions root of the stored block and compares it against the
+    /// transactions root of its header.
+    ///
+    /// Returns `None` if the block is not found.
+    fn verify_block_tx_root(&self, id: BlockHashOrNumber) -> ProviderResult<Option<bool>> {
+        Ok(self
+            .block(id)?
+            .map(|block| block.body.calculate_tx_root() == block.header.transactions_root))
+    }
+
+    /// Returns the total gas used by the block's transactions, read from the cumulative gas used
+    /// of the block's last receipt. Empty blocks used no gas.
+    ///
+    /// Returns `None` if the block is not found, and [`ProviderError::ReceiptNotFound`] if the
+    /// last receipt is missing, e.g. because it was pruned.
+    fn block_gas_used(&self, number: BlockNumber) -> ProviderResult<Option<u64>> {
+        let Some(indices) = self.block_body_indices(number)? else { return Ok(None) };
+        if indices.is_empty() {
+            return Ok(Some(0))
+        }
+
+        let last_tx_num = indices.last_tx_num();
+        let receipt =
+            self.receipt(last_tx_num)?.ok_or(ProviderError::ReceiptNotFound(last_tx_num.into()))?;
+        Ok(Some(receipt.cumulative_gas_used))
+    }
+
+    /// Returns the total gas used by each block in the `range`, see [`Self::block_gas_used`].
+    ///
+    /// Only the last receipt of every block is read. Blocks are returned in ascending block number
+    /// order until the first block that is not found, and an unbounded range ends at the last
+    /// block.
+    fn cumulative_gas_used(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, u64)>> {
+        let Some((start, end)) = block_range_bounds(range, || self.last_block_number())? else {
+            return Ok(Vec::new())
+        };
+
+        let mut gas_used = Vec::new();
+        for number in start..=end {
+            let Some(block_gas_used) = self.block_gas_used(number)? else { break };
+            gas_used.push((number, block_gas_used));
+        }
+        Ok(gas_used)
+    }
+}
+
+impl<T: BlockReader> BlockReaderExt for T {}
+
 /// Trait extension for `BlockReader`, for types that implement `BlockId` conversion.
 ///
 /// The `BlockReader` trait should be implemented on types that can retrieve a block from either
@@ -266,4 +564,82 @@ pub trait BlockReaderIdExt: BlockReader + ReceiptProviderIdExt {
     ///
     /// Returns `None` if block is not found.
     fn ommers_by_id(&self, id: BlockId) -> ProviderResult<Option<Vec<Header>>>;
+
+    /// Returns all transactions of the block with the matching tag, each paired with its receipt
+    /// and [`ReceiptMeta`].
+    ///
+    /// Returns `None` if the block is not found or its receipts are not available, e.g. because
+    /// they were pruned.
+    #[allow(clippy::type_complexity)]
+    fn block_receipts_with_tx_meta(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> ProviderResult<Option<Vec<(TransactionSigned, Receipt, ReceiptMeta)>>> {
+        let Some(number) = self.convert_block_number(block)? else { return Ok(None) };
+        let Some(transactions) = self.transactions_by_block(number.into())? else {
+            return Ok(None)
+        };
+        let Some(receipts) = self.receipts_by_block(number.into())? else { return Ok(None) };
+        if transactions.len() != receipts.len() {
+            return Ok(None)
+        }
+
+        let mut previous_cumulative_gas_used = 0;
+        let mut log_index_base = 0;
+        let joined = transactions
+            .into_iter()
+            .zip(receipts)
+            .enumerate()
+            .map(|(index, (transaction, receipt))| {
+                let meta = ReceiptMeta {
+                    index: index as u64,
+                    gas_used: receipt.cumulative_gas_used - previous_cumulative_gas_used,
+                    cumulative_gas_used: receipt.cumulative_gas_used,
+                    log_index_base,
+                };
+                previous_cumulative_gas_used = receipt.cumulative_gas_used;
+                log_index_base += receipt.logs.len() as u64;
+                (transaction, receipt, meta)
+            })
+            .collect();
+
+        Ok(Some(joined))
+    }
+
+    /// Returns the transaction with the given hash together with its receipt and [`ReceiptMeta`].
+    ///
+    /// The hash is resolved to a transaction number once, and only the receipts preceding the
+    /// transaction in its block are read to compute the metadata.
+    ///
+    /// Returns `None` if the transaction is not found or its receipt or any of the preceding
+    /// receipts in its block are not available, e.g. because they were pruned.
+    fn transaction_and_receipt(
+        &self,
+        hash: B256,
+    ) -> ProviderResult<Option<(TransactionSigned, Receipt, ReceiptMeta)>> {
+        let Some(id) = self.transaction_id(hash)? else { return Ok(None) };
+        let Some(transaction) = self.transaction_by_id(id)? else { return Ok(None) };
+        let Some(receipt) = self.receipt(id)? else { return Ok(None) };
+        let Some(block_number) = self.transaction_block(id)? else { return Ok(None) };
+        let Some(body_indices) = self.block_body_indices(block_number)? else { return Ok(None) };
+
+        let preceding_receipts = self.receipts_by_tx_range(body_indices.first_tx_num()..id)?;
+        if preceding_receipts.len() as u64 != id - body_indices.first_tx_num() {
+            // Some of the preceding receipts were pruned, so the metadata can't be computed
+            return Ok(None)
+        }
+        let previous_cumulative_gas_used =
+            preceding_receipts.last().map_or(0, |receipt| receipt.cumulative_gas_used);
+        let meta = ReceiptMeta {
+            index: id - body_indices.first_tx_num(),
+            gas_used: receipt.cumulative_gas_used - previous_cumulative_gas_used,
+            cumulative_gas_used: receipt.cumulative_gas_used,
+            log_index_base: preceding_receipts
+                .iter()
+                .map(|receipt| receipt.logs.len() as u64)
+                .sum(),
+        };
+
+        Ok(Some((transaction, receipt, meta)))
+    }
 }
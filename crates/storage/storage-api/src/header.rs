@@ -1,8 +1,8 @@
 use alloy_consensus::Header;
 use alloy_eips::BlockHashOrNumber;
-use alloy_primitives::{BlockHash, BlockNumber, U256};
+use alloy_primitives::{BlockHash, BlockNumber, B256, U256};
 use reth_primitives::SealedHeader;
-use reth_storage_errors::provider::ProviderResult;
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
 use std::ops::RangeBounds;
 
 /// Client trait for fetching `Header` related data.
@@ -16,14 +16,95 @@ pub trait HeaderProvider: Send + Sync {
     /// Get header by block hash
     fn header(&self, block_hash: &BlockHash) -> ProviderResult<Option<Header>>;
 
+    /// Get headers by block hashes.
+    ///
+    /// Returns the headers in the order of the input hashes, with `None` if the header isn't
+    /// found. A more efficient version than calling [`Self::header`] repeatedly.
+    fn headers_by_hashes(&self, hashes: &[BlockHash]) -> ProviderResult<Vec<Option<Header>>> {
+        hashes.iter().map(|hash| self.header(hash)).collect()
+    }
+
     /// Retrieves the header sealed by the given block hash.
     fn sealed_header_by_hash(&self, block_hash: BlockHash) -> ProviderResult<Option<SealedHeader>> {
         Ok(self.header(&block_hash)?.map(|header| SealedHeader::new(header, block_hash)))
     }
 
+    /// Get up to `count` ancestors of the block with the given hash, following the parent hashes
+    /// and reading each header by hash, ordered from the parent down.
+    ///
+    /// Stops early at genesis. Unlike iterating by block numbers, this verifies the continuity of
+    /// the hash chain: returns [`ProviderError::HeaderNotFound`] if the header of `start_hash` or
+    /// of any parent on the way is missing.
+    fn header_ancestors(
+        &self,
+        start_hash: BlockHash,
+        count: usize,
+    ) -> ProviderResult<Vec<SealedHeader>> {
+        let header = self
+            .header(&start_hash)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(start_hash.into()))?;
+        let (mut number, mut parent_hash) = (header.number, header.parent_hash);
+
+        let mut ancestors = Vec::with_capacity(count.min(number as usize));
+        while ancestors.len() < count && number > 0 {
+            let parent = self
+                .header(&parent_hash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(parent_hash.into()))?;
+            number = parent.number;
+            let grandparent_hash = parent.parent_hash;
+            ancestors.push(SealedHeader::new(parent, parent_hash));
+            parent_hash = grandparent_hash;
+        }
+        Ok(ancestors)
+    }
+
     /// Get header by block number
     fn header_by_number(&self, num: u64) -> ProviderResult<Option<Header>>;
 
+    /// Get the last sealed header with a timestamp not later than `timestamp`, i.e. the tip of the
+    /// chain at that time.
+    ///
+    /// Binary searches the headers between genesis and the latest block, finding the latest block
+    /// exponentially first. This relies on the timestamps increasing with the block numbers, as on
+    /// post-merge chains; the result is undefined if a chain allows non-monotonic timestamps.
+    ///
+    /// Returns `None` if the timestamp is before genesis, or there are no headers.
+    fn header_by_timestamp(&self, timestamp: u64) -> ProviderResult<Option<SealedHeader>> {
+        let Some(mut low) = self.sealed_header(0)?.filter(|header| header.timestamp <= timestamp)
+        else {
+            return Ok(None)
+        };
+
+        // The header at `high` is either missing or later than the timestamp
+        let mut step = 1;
+        let mut high = loop {
+            let number = low.number.saturating_add(step);
+            match self.sealed_header(number)? {
+                Some(header) if header.timestamp <= timestamp && number > low.number => {
+                    low = header;
+                    step = step.saturating_mul(2);
+                }
+                _ => break number,
+            }
+        };
+
+        while high - low.number > 1 {
+            let middle = low.number + (high - low.number) / 2;
+            match self.sealed_header(middle)? {
+                Some(header) if header.timestamp <= timestamp => low = header,
+                _ => high = middle,
+            }
+        }
+        Ok(Some(low))
+    }
+
+    /// Get the state root of the block with the given number, as recorded in its header.
+    ///
+    /// Returns `None` if the block is not found.
+    fn state_root_by_block(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        Ok(self.header_by_number(number)?.map(|header| header.state_root))
+    }
+
     /// Get header by block number or hash
     fn header_by_hash_or_number(
         &self,
@@ -47,6 +128,14 @@ pub trait HeaderProvider: Send + Sync {
     /// Get a single sealed header by block number.
     fn sealed_header(&self, number: BlockNumber) -> ProviderResult<Option<SealedHeader>>;
 
+    /// Get the canonical hash of the block with the given number.
+    ///
+    /// Returns `None` if there's no canonical block with this number. Providers backed by the
+    /// database read the hash from the canonical hashes directly, without decoding the header.
+    fn canonical_hash(&self, number: BlockNumber) -> ProviderResult<Option<BlockHash>> {
+        Ok(self.sealed_header(number)?.map(|header| header.hash()))
+    }
+
     /// Get headers in range of block numbers.
     fn sealed_headers_range(
         &self,
@@ -55,6 +144,34 @@ pub trait HeaderProvider: Send + Sync {
         self.sealed_headers_while(range, |_| true)
     }
 
+    /// Get a page of up to `limit` sealed headers, starting right after the block number `after`,
+    /// or from genesis if `after` is `None`.
+    ///
+    /// Returns the headers along with the cursor to pass as `after` to get the next page, which is
+    /// the number of the last returned header. The cursor is `None` if there are no more headers,
+    /// or if `limit` is zero, so that paginating with a zero limit doesn't loop forever.
+    fn headers_page(
+        &self,
+        after: Option<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<(Vec<SealedHeader>, Option<BlockNumber>)> {
+        if limit == 0 {
+            return Ok((Vec::new(), None))
+        }
+
+        let start = match after {
+            Some(after) => match after.checked_add(1) {
+                Some(start) => start,
+                None => return Ok((Vec::new(), None)),
+            },
+            None => 0,
+        };
+        let headers = self.sealed_headers_range(start..start.saturating_add(limit as u64))?;
+        let next =
+            if headers.len() < limit { None } else { headers.last().map(|header| header.number) };
+        Ok((headers, next))
+    }
+
     /// Get sealed headers while `predicate` returns `true` or the range is exhausted.
     fn sealed_headers_while(
         &self,
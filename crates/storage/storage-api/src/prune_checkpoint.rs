@@ -1,3 +1,4 @@
+use alloy_primitives::BlockNumber;
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_storage_errors::provider::ProviderResult;
 
@@ -12,6 +13,24 @@ pub trait PruneCheckpointReader: Send + Sync {
 
     /// Fetch all the prune checkpoints.
     fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>>;
+
+    /// Returns the earliest block which data of the given segment is still available, i.e. the
+    /// block after the prune checkpoint of the segment, or zero if the segment was never pruned.
+    ///
+    /// Headers and transactions are only pruned from the database after they were moved to static
+    /// files, so they're always available from the genesis. The receipts checkpoint is also
+    /// advanced when the receipts are moved to static files, in which case the returned floor is
+    /// conservative.
+    fn availability_floor(&self, segment: PruneSegment) -> ProviderResult<BlockNumber> {
+        if matches!(segment, PruneSegment::Headers | PruneSegment::Transactions) {
+            return Ok(0)
+        }
+
+        Ok(self
+            .get_prune_checkpoint(segment)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .map_or(0, |block_number| block_number + 1))
+    }
 }
 
 /// The trait for updating prune checkpoint related data.
@@ -1,9 +1,17 @@
-use crate::BlockIdReader;
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
-use alloy_primitives::{BlockNumber, TxHash, TxNumber};
-use reth_primitives::Receipt;
-use reth_storage_errors::provider::ProviderResult;
-use std::ops::RangeBounds;
+use crate::{BlockIdReader, BlockReaderIdExt, TransactionsProvider};
+use alloy_eips::{eip2718::Encodable2718, BlockHashOrNumber, BlockId, BlockNumberOrTag};
+use alloy_primitives::{BlockNumber, Bloom, BloomInput, Bytes, TxHash, TxNumber, B256};
+use alloy_rpc_types_eth::{Filter, FilterBlockOption, FilteredParams, Log};
+use reth_primitives::{proofs::calculate_receipt_root_no_memo, Receipt};
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use std::ops::{Range, RangeBounds};
+
+/// Number of transactions read at once by [`receipts_with_tx_hash_chunks`].
+const RECEIPTS_WITH_TX_HASH_CHUNK_SIZE: u64 = 1_000;
+
+/// Maximum number of blocks searched by [`logs_by_header_blooms`], the same as the default limit of
+/// `eth_getLogs`.
+pub const MAX_BLOCKS_PER_LOGS_FILTER: u64 = 100_000;
 
 /// Client trait for fetching [Receipt] data .
 #[auto_impl::auto_impl(&, Arc)]
@@ -13,6 +21,25 @@ pub trait ReceiptProvider: Send + Sync {
     /// Returns `None` if the transaction is not found.
     fn receipt(&self, id: TxNumber) -> ProviderResult<Option<Receipt>>;
 
+    /// Get the logs bloom of a receipt by transaction number.
+    ///
+    /// Receipts are stored without their bloom, so by default it's computed from the receipt logs.
+    ///
+    /// Returns `None` if the transaction is not found.
+    fn receipt_bloom(&self, id: TxNumber) -> ProviderResult<Option<Bloom>> {
+        Ok(self.receipt(id)?.map(|receipt| receipt.bloom_slow()))
+    }
+
+    /// Get the raw EIP-2718 encoded receipt, including its logs bloom, by transaction number.
+    ///
+    /// This is the encoding returned by `debug_getRawReceipts`, so the bytes can be forwarded or
+    /// checksummed as-is. By default, it's encoded from [`Self::receipt`].
+    ///
+    /// Returns `None` if the transaction is not found.
+    fn raw_receipt_by_id(&self, id: TxNumber) -> ProviderResult<Option<Bytes>> {
+        Ok(self.receipt(id)?.map(|receipt| receipt.with_bloom().encoded_2718().into()))
+    }
+
     /// Get receipt by transaction hash.
     ///
     /// Returns `None` if the transaction is not found.
@@ -24,10 +51,225 @@ pub trait ReceiptProvider: Send + Sync {
     fn receipts_by_block(&self, block: BlockHashOrNumber) -> ProviderResult<Option<Vec<Receipt>>>;
 
     /// Get receipts by tx range.
+    ///
+    /// Missing receipts, e.g. pruned ones, are skipped instead of returning an error, so the
+    /// result is shorter than the range if a part of it was pruned, and empty if all of it was.
     fn receipts_by_tx_range(
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Receipt>>;
+
+    /// Streams receipts by tx range, joined with the hashes of their transactions.
+    ///
+    /// Items are yielded in ascending transaction number order, exactly one per transaction in
+    /// the range, and the stream ends at the last transaction available. Receipts and transactions
+    /// are read together in chunks, so no per-receipt hash lookups are made. The stream yields
+    /// [`ProviderError::ReceiptNotFound`] and ends if a transaction has no receipt, e.g. because
+    /// the receipts were pruned.
+    ///
+    /// By default, the provider doesn't support streaming receipts and returns
+    /// [`ProviderError::UnsupportedProvider`].
+    fn receipts_with_tx_hash_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(B256, Receipt)>>> {
+        let _ = range;
+        Err::<std::iter::Empty<_>, _>(ProviderError::UnsupportedProvider)
+    }
+
+    /// Returns the logs matching the filter, like `eth_getLogs`.
+    ///
+    /// Missing bounds of the block range default to the latest block, and the range ends at the
+    /// latest block at most. Returns [`ProviderError::BlockRangeTooLarge`] if the range spans more
+    /// than [`MAX_BLOCKS_PER_LOGS_FILTER`] blocks.
+    ///
+    /// By default, the provider doesn't support filtering logs and returns
+    /// [`ProviderError::UnsupportedProvider`].
+    fn logs_by_filter(&self, filter: &Filter) -> ProviderResult<Vec<Log>> {
+        let _ = filter;
+        Err(ProviderError::UnsupportedProvider)
+    }
+}
+
+/// Streams the receipts in the `range` joined with the hashes of their transactions, reading both
+/// from the `provider` in chunks.
+///
+/// Implements [`ReceiptProvider::receipts_with_tx_hash_range`] for providers that can also read
+/// transactions.
+pub fn receipts_with_tx_hash_chunks<P>(
+    provider: P,
+    range: Range<TxNumber>,
+) -> impl Iterator<Item = ProviderResult<(B256, Receipt)>>
+where
+    P: ReceiptProvider + TransactionsProvider,
+{
+    let mut next = range.start;
+    let mut chunk = Vec::new().into_iter();
+    std::iter::from_fn(move || loop {
+        if let Some(item) = chunk.next() {
+            return Some(Ok(item))
+        }
+        if next >= range.end {
+            return None
+        }
+
+        let chunk_range =
+            next..range.end.min(next.saturating_add(RECEIPTS_WITH_TX_HASH_CHUNK_SIZE));
+        let result =
+            provider.transactions_by_tx_range(chunk_range.clone()).and_then(|transactions| {
+                let receipts = provider.receipts_by_tx_range(chunk_range.clone())?;
+                if receipts.len() < transactions.len() {
+                    let missing = chunk_range.start + receipts.len() as u64;
+                    return Err(ProviderError::ReceiptNotFound(missing.into()))
+                }
+                Ok(transactions.into_iter().map(|tx| tx.hash()).zip(receipts).collect::<Vec<_>>())
+            });
+        match result {
+            // Fewer transactions than requested means that the end of the chain was reached
+            Ok(items) => {
+                next = if (items.len() as u64) < chunk_range.end - chunk_range.start {
+                    range.end
+                } else {
+                    chunk_range.end
+                };
+                chunk = items.into_iter();
+            }
+            Err(err) => {
+                next = range.end;
+                return Some(Err(err))
+            }
+        }
+    })
+}
+
+/// Returns the logs matching the `filter`, searching the blocks of the `provider` one by one.
+///
+/// The receipts of a block are only read if the logs bloom of its header matches the
+/// [`PreparedFilter`] of the filter, and the logs of the matching blocks then get the full address
+/// and topic match. Blocks with pruned receipts are skipped.
+///
+/// Implements [`ReceiptProvider::logs_by_filter`] for providers that can also read headers and
+/// transactions, and resolve block tags.
+pub fn logs_by_header_blooms<P>(provider: &P, filter: &Filter) -> ProviderResult<Vec<Log>>
+where
+    P: BlockReaderIdExt,
+{
+    let best_block = provider.best_block_number()?;
+    let (from_block, to_block) = match filter.block_option {
+        FilterBlockOption::AtBlockHash(hash) => {
+            let number =
+                provider.block_number(hash)?.ok_or(ProviderError::BlockHashNotFound(hash))?;
+            (number, number)
+        }
+        FilterBlockOption::Range { from_block, to_block } => {
+            let convert = |block: Option<BlockNumberOrTag>| {
+                provider
+                    .convert_block_number(block.unwrap_or_default())
+                    .map(|number| number.unwrap_or(best_block))
+            };
+            (convert(from_block)?, convert(to_block)?.min(best_block))
+        }
+    };
+    if to_block.saturating_sub(from_block) > MAX_BLOCKS_PER_LOGS_FILTER {
+        return Err(ProviderError::BlockRangeTooLarge {
+            from_block,
+            to_block,
+            max_blocks: MAX_BLOCKS_PER_LOGS_FILTER,
+        })
+    }
+
+    let params = FilteredParams::new(Some(filter.clone()));
+    let prepared = PreparedFilter::new(&params);
+
+    let mut logs = Vec::new();
+    for number in from_block..=to_block {
+        let Some(header) = provider.sealed_header(number)? else { continue };
+        if !prepared.matches(header.logs_bloom) {
+            continue
+        }
+
+        let Some(receipts) = provider.receipts_by_block(number.into())? else { continue };
+        let Some(transactions) = provider.transactions_by_block(number.into())? else { continue };
+        if transactions.len() != receipts.len() {
+            continue
+        }
+
+        let mut log_index = 0;
+        for (index, (transaction, receipt)) in transactions.iter().zip(&receipts).enumerate() {
+            for log in &receipt.logs {
+                if params.filter_address(&log.address) && params.filter_topics(log.topics()) {
+                    logs.push(Log {
+                        inner: log.clone(),
+                        block_hash: Some(header.hash()),
+                        block_number: Some(number),
+                        block_timestamp: Some(header.timestamp),
+                        transaction_hash: Some(transaction.hash()),
+                        transaction_index: Some(index as u64),
+                        log_index: Some(log_index),
+                        removed: false,
+                    });
+                }
+                log_index += 1;
+            }
+        }
+    }
+    Ok(logs)
+}
+
+/// Bloom masks of the addresses and topics of [`FilteredParams`], computed once to check the blooms
+/// of many headers, e.g. when searching a range of blocks for logs.
+///
+/// Matches the same blooms as [`FilteredParams::matches_address`] and
+/// [`FilteredParams::matches_topics`] combined, but without rebuilding the filters per bloom.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreparedFilter {
+    /// Masks of the addresses. A matching bloom contains any of them, or the list is empty.
+    addresses: Vec<Bloom>,
+    /// Masks of the topics per position that isn't a wildcard. A matching bloom contains any
+    /// mask of every position.
+    topics: Vec<Vec<Bloom>>,
+}
+
+impl PreparedFilter {
+    /// Precomputes the bloom masks of the filter. Params without a filter match any bloom.
+    pub fn new(params: &FilteredParams) -> Self {
+        let Some(filter) = &params.filter else { return Self::default() };
+        Self {
+            addresses: filter
+                .address
+                .iter()
+                .map(|address| BloomInput::Raw(address.as_slice()).into())
+                .collect(),
+            topics: filter
+                .topics
+                .iter()
+                .filter(|topic| !topic.is_empty())
+                .map(|topic| {
+                    topic.iter().map(|topic| BloomInput::Raw(topic.as_slice()).into()).collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if the bloom may contain logs matching the filter.
+    pub fn matches(&self, bloom: Bloom) -> bool {
+        let contains_any =
+            |masks: &[Bloom]| masks.is_empty() || masks.iter().any(|mask| bloom.contains(mask));
+        contains_any(&self.addresses) && self.topics.iter().all(|masks| contains_any(masks))
+    }
+}
+
+/// Metadata of a receipt within its block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReceiptMeta {
+    /// Index of the transaction in the block.
+    pub index: u64,
+    /// Gas used by the transaction.
+    pub gas_used: u64,
+    /// Gas used by the transaction and all preceding transactions in the block.
+    pub cumulative_gas_used: u64,
+    /// Index of the first log of the receipt within the block.
+    pub log_index_base: u64,
 }
 
 /// Trait extension for `ReceiptProvider`, for types that implement `BlockId` conversion.
@@ -67,6 +309,16 @@ pub trait ReceiptProviderIdExt: ReceiptProvider + BlockIdReader {
     ) -> ProviderResult<Option<Vec<Receipt>>> {
         self.receipts_by_block_id(number_or_tag.into())
     }
+
+    /// Computes the receipts root of the block with the matching number or tag from the stored
+    /// receipts.
+    ///
+    /// Returns `None` if block is not found.
+    fn calculate_receipts_root(&self, block: BlockNumberOrTag) -> ProviderResult<Option<B256>> {
+        Ok(self
+            .receipts_by_number_or_tag(block)?
+            .map(|receipts| calculate_receipt_root_no_memo(&receipts.iter().collect::<Vec<_>>())))
+    }
 }
 
 /// Writer trait for writing [`Receipt`] data.
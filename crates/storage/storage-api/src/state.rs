@@ -4,11 +4,14 @@ use super::{
 };
 use alloy_consensus::constants::KECCAK_EMPTY;
 use alloy_eips::{BlockId, BlockNumHash, BlockNumberOrTag};
-use alloy_primitives::{Address, BlockHash, BlockNumber, StorageKey, StorageValue, B256, U256};
+use alloy_primitives::{
+    keccak256, Address, BlockHash, BlockNumber, StorageKey, StorageValue, B256, U256,
+};
 use auto_impl::auto_impl;
 use reth_execution_types::ExecutionOutcome;
-use reth_primitives::Bytecode;
+use reth_primitives::{Account, Bytecode};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use reth_trie::{AccountProof, HashedPostState, TrieInput};
 
 /// Type alias of boxed [`StateProvider`].
 pub type StateProviderBox = Box<dyn StateProvider>;
@@ -34,6 +37,24 @@ pub trait StateProvider:
     /// Get account code by its hash
     fn bytecode_by_hash(&self, code_hash: B256) -> ProviderResult<Option<Bytecode>>;
 
+    /// Computes the state root of the current state from the hashed state, without any changes
+    /// on top of it.
+    ///
+    /// The result can be compared against the state root of the block header the state
+    /// corresponds to, see
+    /// [`HeaderProvider::state_root_by_block`](crate::HeaderProvider::state_root_by_block).
+    fn compute_state_root(&self) -> ProviderResult<B256> {
+        self.state_root(HashedPostState::default())
+    }
+
+    /// Get the account proof and the storage proofs of the given `slots`, as returned by
+    /// `eth_getProof`, against the current state without any changes on top of it.
+    ///
+    /// The proofs can be verified against [`Self::compute_state_root`].
+    fn account_proof(&self, address: Address, slots: &[B256]) -> ProviderResult<AccountProof> {
+        self.proof(TrieInput::default(), address, slots)
+    }
+
     /// Get account code by its address.
     ///
     /// Returns `None` if the account doesn't exist or account is not a contract
@@ -80,6 +101,36 @@ pub trait StateProvider:
             None => Ok(None),
         }
     }
+
+    /// Returns up to `limit` addresses of accounts whose bytecode hash is `code_hash`.
+    ///
+    /// There's no index by code hash, so this is a full scan over all accounts and is very
+    /// expensive on a synced node. Providers that can't scan the state return
+    /// [`ProviderError::UnsupportedProvider`].
+    fn accounts_by_code_hash(
+        &self,
+        _code_hash: B256,
+        _limit: usize,
+    ) -> ProviderResult<Vec<Address>> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    /// Returns the hashed address of the account, as used in the hashed state and the state trie.
+    fn hashed_address(&self, address: Address) -> B256 {
+        keccak256(address)
+    }
+
+    /// Get account by its hashed address, together with the address it was hashed from if its
+    /// preimage is stored.
+    ///
+    /// Returns `None` if the account doesn't exist. Providers without access to the hashed state
+    /// return [`ProviderError::UnsupportedProvider`].
+    fn account_by_hashed_address(
+        &self,
+        _hashed_address: B256,
+    ) -> ProviderResult<Option<(Option<Address>, Account)>> {
+        Err(ProviderError::UnsupportedProvider)
+    }
 }
 
 /// Trait implemented for database providers that can be converted into a historical state provider.
@@ -226,8 +226,7 @@ fn state_provider_example<T: StateProvider + AccountReader>(provider: T) -> eyre
     let _account = provider.basic_account(address)?;
     let _code = provider.account_code(address)?;
     let _storage = provider.storage(address, storage_key)?;
-    // TODO: unimplemented.
-    // let _proof = provider.proof(address, &[])?;
+    let _proof = provider.account_proof(address, &[storage_key])?;
 
     Ok(())
 }